@@ -1,6 +1,20 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
 use chrono::{TimeZone, Utc, Local};
 use date_component::date_component;
+use ::date_component::calendar;
+
+/// Month length found by probing a timezone for the last valid day,
+/// as `calculate` did before the calendar module.
+fn days_in_month_probe<T: TimeZone>(year: i32, month: u32, timezone: &T) -> u32 {
+    let mut subtract = 0;
+    loop {
+        match timezone.with_ymd_and_hms(year, month, 31 - subtract, 0, 0, 0) {
+            chrono::LocalResult::None => subtract += 1,
+            chrono::LocalResult::Single(_) | chrono::LocalResult::Ambiguous(_, _) => return 31 - subtract,
+        }
+    }
+}
 
 fn benchmark_calculate(c: &mut Criterion) {
     let timezone = Utc;
@@ -35,5 +49,74 @@ fn benchmark_calculate(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, benchmark_calculate);
+fn benchmark_days_in_month(c: &mut Criterion) {
+    c.bench_function("days in month calendar", |b| {
+        b.iter(|| (1..=12).map(|month| calendar::days_in_month(black_box(2023), month)).sum::<u32>())
+    });
+
+    c.bench_function("days in month timezone probe", |b| {
+        b.iter(|| (1..=12).map(|month| days_in_month_probe(black_box(2023), month, &Utc)).sum::<u32>())
+    });
+
+    c.bench_function("days in month timezone probe tz", |b| {
+        b.iter(|| (1..=12).map(|month| days_in_month_probe(black_box(2023), month, &chrono_tz::America::Los_Angeles)).sum::<u32>())
+    });
+}
+
+/// The scalar loop against the batch functions, which share the compiled offsets
+/// of the timezone with the `chrono-tz` feature.
+fn benchmark_batch(c: &mut Criterion) {
+    let paris = chrono_tz::Europe::Paris;
+    #[cfg(feature = "chrono-tz")]
+    date_component::CachedTz::new(paris);
+    let start = paris.with_ymd_and_hms(2022, 1, 31, 10, 0, 0).unwrap();
+    let from: Vec<_> = (0..10_000).map(|i| start + chrono::Duration::minutes(i * 97)).collect();
+    let to: Vec<_> = (0..10_000).map(|i| start + chrono::Duration::hours(8_760 - i * 3)).collect();
+
+    c.bench_function("calculate scalar 10k", |b| {
+        b.iter(|| {
+            from.iter()
+                .zip(&to)
+                .map(|(f, t)| date_component::calculate(black_box(f), black_box(t)))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    c.bench_function("calculate_many 10k", |b| {
+        b.iter(|| date_component::calculate_many(black_box(&from), black_box(&to)))
+    });
+
+    c.bench_function("calculate_columns 10k", |b| {
+        b.iter(|| date_component::calculate_columns(black_box(&from), black_box(&to)))
+    });
+}
+
+/// The scalar loop of `benchmark_batch` with the datetimes in the cached timezone,
+/// and the lookup of a compiled timezone.
+/// Needs the `chrono-tz` feature: `cargo bench --features chrono-tz`.
+#[cfg(feature = "chrono-tz")]
+fn benchmark_zone_cache(c: &mut Criterion) {
+    let paris = date_component::CachedTz::new(chrono_tz::Europe::Paris);
+    let start = chrono_tz::Europe::Paris.with_ymd_and_hms(2022, 1, 31, 10, 0, 0).unwrap();
+    let from: Vec<_> = (0..10_000).map(|i| (start + chrono::Duration::minutes(i * 97)).with_timezone(&paris)).collect();
+    let to: Vec<_> = (0..10_000).map(|i| (start + chrono::Duration::hours(8_760 - i * 3)).with_timezone(&paris)).collect();
+
+    c.bench_function("calculate cached tz 10k", |b| {
+        b.iter(|| {
+            from.iter()
+                .zip(&to)
+                .map(|(f, t)| date_component::calculate(black_box(f), black_box(t)))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    c.bench_function("cached tz new", |b| {
+        b.iter(|| date_component::CachedTz::new(black_box(chrono_tz::Europe::Paris)))
+    });
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+criterion_group!(benches, benchmark_calculate, benchmark_days_in_month, benchmark_batch);
+#[cfg(feature = "chrono-tz")]
+criterion_group!(benches, benchmark_calculate, benchmark_days_in_month, benchmark_batch, benchmark_zone_cache);
 criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,75 @@
+#ifndef DATE_COMPONENT_H
+#define DATE_COMPONENT_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Result of the C functions.
+ */
+typedef enum DateComponentStatus {
+  DATE_COMPONENT_STATUS_OK = 0,
+  /**
+   * A pointer argument is null.
+   */
+  DATE_COMPONENT_STATUS_NULL_POINTER = 1,
+  /**
+   * The timezone name is not valid UTF-8.
+   */
+  DATE_COMPONENT_STATUS_INVALID_UTF8 = 2,
+  /**
+   * The timezone name is not an IANA timezone.
+   */
+  DATE_COMPONENT_STATUS_UNKNOWN_TIMEZONE = 3,
+  /**
+   * A timestamp is out of the range supported by chrono.
+   */
+  DATE_COMPONENT_STATUS_TIMESTAMP_OUT_OF_RANGE = 4,
+} DateComponentStatus;
+
+/**
+ * `DateComponent` with a C layout.
+ */
+typedef struct DateComponent {
+  int64_t year;
+  int64_t month;
+  int64_t week;
+  int64_t modulo_days;
+  int64_t day;
+  int64_t hour;
+  int64_t minute;
+  int64_t second;
+  int64_t interval_seconds;
+  int64_t interval_minutes;
+  int64_t interval_hours;
+  int64_t interval_days;
+  bool invert;
+} DateComponent;
+
+/**
+ * Calculates the DateComponent between two Unix timestamps in seconds, in the IANA
+ * timezone `timezone` (e.g. "Asia/Tokyo"), and writes it to `out`.
+ * `out` is left unchanged unless `DATE_COMPONENT_STATUS_OK` is returned.
+ *
+ * # Safety
+ *
+ * `timezone` must be null or a NUL-terminated string, and `out` must be null or
+ * point to a writable `DateComponent`.
+ */
+enum DateComponentStatus date_component_calculate(int64_t from_timestamp,
+                                                  int64_t to_timestamp,
+                                                  const char *timezone,
+                                                  struct DateComponent *out);
+
+/**
+ * Returns a static NUL-terminated description of `status`, a `DateComponentStatus`.
+ * Values that are not a `DateComponentStatus` get a generic description.
+ */
+const char *date_component_status_message(int status);
+
+#endif  /* DATE_COMPONENT_H */
@@ -0,0 +1,135 @@
+//! Apache Arrow kernels computing DateComponents between two timestamp arrays.
+//!
+//! The timezone of each array is read from its metadata (e.g. `+09:00`, or `Asia/Tokyo` with
+//! the `chrono-tz` feature), timestamps without a timezone are treated as UTC. Rows are calculated as by
+//! `date_component::calculate` in the timezone of the from array, and a row is null
+//! when either timestamp is null.
+
+use std::sync::Arc;
+
+use arrow_array::builder::{BooleanBuilder, Int32Builder};
+use arrow_array::temporal_conversions::as_datetime_with_timezone;
+use arrow_array::timezone::Tz;
+use arrow_array::types::{ArrowTimestampType, IntervalMonthDayNano};
+use arrow_array::{Array, ArrayRef, IntervalMonthDayNanoArray, PrimitiveArray, StructArray};
+use arrow_schema::{ArrowError, DataType, Field, Fields};
+
+use crate::date_component::{calculate, DateComponent};
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+/// Fields of the `StructArray` returned by `date_component_kernel`.
+pub fn date_component_fields() -> Fields {
+    Fields::from(vec![
+        Field::new("year", DataType::Int32, true),
+        Field::new("month", DataType::Int32, true),
+        Field::new("day", DataType::Int32, true),
+        Field::new("hour", DataType::Int32, true),
+        Field::new("minute", DataType::Int32, true),
+        Field::new("second", DataType::Int32, true),
+        Field::new("invert", DataType::Boolean, true),
+    ])
+}
+
+/// Returns a `StructArray` of year, month, day, hour, minute, second and invert
+/// between each pair of from and to timestamps.
+pub fn date_component_kernel<T: ArrowTimestampType>(
+    from: &PrimitiveArray<T>,
+    to: &PrimitiveArray<T>,
+) -> Result<StructArray, ArrowError> {
+    let components = calculate_arrays(from, to)?;
+
+    let mut fields: Vec<Int32Builder> = (0..6).map(|_| Int32Builder::with_capacity(components.len())).collect();
+    let mut invert = BooleanBuilder::with_capacity(components.len());
+    for component in &components {
+        match component {
+            Some((c, _)) => {
+                let values = [c.year, c.month, c.day, c.hour, c.minute, c.second];
+                for (builder, value) in fields.iter_mut().zip(values.iter()) {
+                    builder.append_value(*value as i32);
+                }
+                invert.append_value(c.invert);
+            }
+            None => {
+                fields.iter_mut().for_each(|builder| builder.append_null());
+                invert.append_null();
+            }
+        }
+    }
+
+    let mut arrays: Vec<ArrayRef> = fields
+        .iter_mut()
+        .map(|builder| Arc::new(builder.finish()) as ArrayRef)
+        .collect();
+    arrays.push(Arc::new(invert.finish()));
+    let nulls = arrays[0].logical_nulls();
+    StructArray::try_new(date_component_fields(), arrays, nulls)
+}
+
+/// Returns the Arrow `MonthDayNano` interval between each pair of from and to timestamps.
+/// Years are counted as 12 months, the nanoseconds keep the fraction of a second of the
+/// timestamps, and all parts are negative when the interval is inverted.
+pub fn month_day_nano_kernel<T: ArrowTimestampType>(
+    from: &PrimitiveArray<T>,
+    to: &PrimitiveArray<T>,
+) -> Result<IntervalMonthDayNanoArray, ArrowError> {
+    let components = calculate_arrays(from, to)?;
+    Ok(components
+        .iter()
+        .map(|component| component.as_ref().map(|(c, nanos)| to_month_day_nano(c, *nanos)))
+        .collect())
+}
+
+fn to_month_day_nano(component: &DateComponent, subsec_nanos: i64) -> IntervalMonthDayNano {
+    let sign = if component.invert { -1 } else { 1 };
+    let seconds = component.hour as i64 * 3600 + component.minute as i64 * 60 + component.second as i64;
+    IntervalMonthDayNano::new(
+        sign * (component.year * 12 + component.month) as i32,
+        sign * component.day as i32,
+        sign as i64 * (seconds * NANOS_PER_SECOND + subsec_nanos),
+    )
+}
+
+/// Returns the DateComponent of each pair, with the nanoseconds of its interval
+/// beyond the whole seconds, which `calculate` drops.
+fn calculate_arrays<T: ArrowTimestampType>(
+    from: &PrimitiveArray<T>,
+    to: &PrimitiveArray<T>,
+) -> Result<Vec<Option<(DateComponent, i64)>>, ArrowError> {
+    if from.len() != to.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "from and to must have the same length, got {} and {}",
+            from.len(),
+            to.len()
+        )));
+    }
+    let from_timezone = timezone(from)?;
+    let to_timezone = timezone(to)?;
+
+    (0..from.len())
+        .map(|i| {
+            if from.is_null(i) || to.is_null(i) {
+                return Ok(None);
+            }
+            let from_datetime = as_datetime_with_timezone::<T>(from.value(i), from_timezone);
+            let to_datetime = as_datetime_with_timezone::<T>(to.value(i), to_timezone);
+            match (from_datetime, to_datetime) {
+                (Some(from_datetime), Some(to_datetime)) => {
+                    let component = calculate(&from_datetime, &to_datetime);
+                    let (earlier, later) = if component.invert {
+                        (to_datetime.timestamp_subsec_nanos(), from_datetime.timestamp_subsec_nanos())
+                    } else {
+                        (from_datetime.timestamp_subsec_nanos(), to_datetime.timestamp_subsec_nanos())
+                    };
+                    let subsec_nanos = (later as i64 - earlier as i64).rem_euclid(NANOS_PER_SECOND);
+                    Ok(Some((component, subsec_nanos)))
+                }
+                _ => Err(ArrowError::ComputeError(format!("timestamp out of range at index {}", i))),
+            }
+        })
+        .collect()
+}
+
+fn timezone<T: ArrowTimestampType>(array: &PrimitiveArray<T>) -> Result<Tz, ArrowError> {
+    array.timezone().unwrap_or("+00:00").parse()
+}
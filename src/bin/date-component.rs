@@ -0,0 +1,121 @@
+//! Command-line tool computing the DateComponent between two dates.
+//!
+//! ```text
+//! date-component 2023-01-31T10:00 2024-03-01 --tz Asia/Tokyo --format iso
+//! date-component 2023-01-31T10:00 --add P1M2DT3H --tz Asia/Tokyo
+//! ```
+
+use std::convert::TryFrom;
+use std::process;
+
+use clap::{Parser, ValueEnum};
+use date_component::date_component::*;
+
+/// Calculates the years, months, days, hours, minutes and seconds between two dates.
+#[derive(Debug, Parser)]
+#[command(name = "date-component", version)]
+struct Args {
+    /// Start date, as RFC 3339 (2023-01-31T10:00:00+09:00) or ISO 8601 local time
+    /// (2023-01-31T10:00, 2023-01-31). Local times skipped by a DST gap are moved forward.
+    from: String,
+
+    /// End date, in the same formats as the start date.
+    #[arg(required_unless_present = "add", conflicts_with = "add")]
+    to: Option<String>,
+
+    /// IANA timezone in which local dates are read and the difference is calculated.
+    #[arg(long, default_value = "UTC")]
+    tz: String,
+
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Print the start date moved by this duration instead, e.g. P1M2DT3H or "1 mon 2 days".
+    #[arg(long, value_name = "DURATION", allow_hyphen_values = true)]
+    add: Option<String>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// One field per line.
+    Table,
+    /// A JSON object.
+    Json,
+    /// An ISO 8601 duration, e.g. P1Y2M3DT4H.
+    Iso,
+    /// Words, e.g. 1 year, 2 months and 3 days.
+    Human,
+}
+
+fn main() {
+    let args = Args::parse();
+    match run(&args) {
+        Ok(output) => println!("{}", output),
+        Err(message) => {
+            eprintln!("date-component: {}", message);
+            process::exit(1);
+        }
+    }
+}
+
+fn run(args: &Args) -> Result<String, String> {
+    let timezone = parse_timezone(&args.tz).map_err(|e| e.to_string())?;
+    let from = parse_datetime(&args.from, &timezone).map_err(|e| e.to_string())?;
+
+    if let Some(duration) = &args.add {
+        let interval: PostgresInterval = duration
+            .parse()
+            .map_err(|_| format!("invalid duration {:?}", duration))?;
+        let component = DateComponent::try_from(interval).map_err(|e| e.to_string())?;
+        let moved = component
+            .apply_to(&from, Disambiguation::Compatible)
+            .map_err(|e| e.to_string())?;
+        return Ok(moved.to_rfc3339());
+    }
+
+    let to = parse_datetime(args.to.as_deref().unwrap_or_default(), &timezone).map_err(|e| e.to_string())?;
+    let component = calculate(&from, &to);
+    Ok(match args.format {
+        Format::Table => table(&component),
+        Format::Json => json(&component),
+        Format::Iso => PostgresInterval::try_from(component)
+            .map_err(|e| e.to_string())?
+            .format(IntervalStyle::Iso8601),
+        Format::Human => component.humanize(),
+    })
+}
+
+fn fields(component: &DateComponent) -> Vec<(&'static str, String)> {
+    vec![
+        ("year", component.year.to_string()),
+        ("month", component.month.to_string()),
+        ("week", component.week.to_string()),
+        ("modulo_days", component.modulo_days.to_string()),
+        ("day", component.day.to_string()),
+        ("hour", component.hour.to_string()),
+        ("minute", component.minute.to_string()),
+        ("second", component.second.to_string()),
+        ("interval_seconds", component.interval_seconds.to_string()),
+        ("interval_minutes", component.interval_minutes.to_string()),
+        ("interval_hours", component.interval_hours.to_string()),
+        ("interval_days", component.interval_days.to_string()),
+        ("invert", component.invert.to_string()),
+    ]
+}
+
+fn table(component: &DateComponent) -> String {
+    fields(component)
+        .iter()
+        .map(|(name, value)| format!("{:<16} {}", name, value))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn json(component: &DateComponent) -> String {
+    let members: Vec<String> = fields(component)
+        .iter()
+        .map(|(name, value)| format!("\"{}\":{}", name, value))
+        .collect();
+    format!("{{{}}}", members.join(","))
+}
@@ -0,0 +1,61 @@
+//! Proleptic Gregorian calendar arithmetic, independent of any timezone.
+//! All functions are `const fn` and can be evaluated at compile time.
+
+/// Cumulative number of days before each month in a common year.
+const DAYS_BEFORE_MONTH: [u32; 13] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334, 365];
+
+/// Returns true if `year` is a leap year in the proleptic Gregorian calendar.
+pub const fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days in `year` (365 or 366).
+pub const fn days_in_year(year: i32) -> u32 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// Returns the number of days in `month` (1 to 12) of `year`.
+///
+/// Panics if `month` is out of range.
+pub const fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+        _ => panic!("month out of range"),
+    }
+}
+
+/// Returns the day of the year (1 to 366) of the given date, also known as the ordinal date.
+///
+/// Panics if `month` is out of range.
+pub const fn day_of_year(year: i32, month: u32, day: u32) -> u32 {
+    assert!(month >= 1 && month <= 12, "month out of range");
+    let leap_day = if month > 2 && is_leap_year(year) { 1 } else { 0 };
+    DAYS_BEFORE_MONTH[(month - 1) as usize] + leap_day + day
+}
+
+/// Returns the month and day of the `ordinal` day of `year`,
+/// or `None` if `ordinal` is not between 1 and the number of days in the year.
+pub const fn from_day_of_year(year: i32, ordinal: u32) -> Option<(u32, u32)> {
+    if ordinal < 1 || ordinal > days_in_year(year) {
+        return None;
+    }
+    let mut month = 1;
+    let mut remaining = ordinal;
+    while remaining > days_in_month(year, month) {
+        remaining -= days_in_month(year, month);
+        month += 1;
+    }
+    Some((month, remaining))
+}
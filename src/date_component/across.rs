@@ -0,0 +1,60 @@
+use chrono::prelude::*;
+#[cfg(feature = "chrono-tz")]
+use chrono_tz::Tz;
+
+use super::{calculate, DateComponent};
+#[cfg(feature = "chrono-tz")]
+use super::timezone::{parse_timezone, UnknownTimezoneError};
+
+/// Timezone in which `calculate_across` compares the calendar dates.
+///
+/// Non-exhaustive because `Explicit` only exists with the `chrono-tz` feature,
+/// and enabling a feature must not break matches on this enum.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ZonePolicy {
+    /// Use the timezone of the from datetime.
+    FromZone,
+    /// Use the timezone of the to datetime.
+    ToZone,
+    /// Use UTC.
+    Utc,
+    /// Use the given timezone.
+    #[cfg(feature = "chrono-tz")]
+    Explicit(Tz),
+}
+
+#[cfg(feature = "chrono-tz")]
+impl ZonePolicy {
+    /// Returns the policy using the timezone of the IANA timezone database named `name`,
+    /// e.g. `Asia/Tokyo`.
+    pub fn named(name: &str) -> Result<Self, UnknownTimezoneError> {
+        parse_timezone(name).map(ZonePolicy::Explicit)
+    }
+}
+
+/// Returns a DateComponent object that represents the difference between the from and to datetime,
+/// which may be in different timezones. Both are converted to the timezone chosen by `policy`
+/// before the calendar difference is calculated.
+pub fn calculate_across<A: TimeZone, B: TimeZone>(
+    from_datetime: &DateTime<A>,
+    to_datetime: &DateTime<B>,
+    policy: ZonePolicy,
+) -> DateComponent {
+    match policy {
+        ZonePolicy::FromZone => {
+            let timezone = from_datetime.timezone();
+            calculate(from_datetime, &to_datetime.with_timezone(&timezone))
+        }
+        ZonePolicy::ToZone => {
+            let timezone = to_datetime.timezone();
+            calculate(&from_datetime.with_timezone(&timezone), to_datetime)
+        }
+        ZonePolicy::Utc => calculate(&from_datetime.with_timezone(&Utc), &to_datetime.with_timezone(&Utc)),
+        #[cfg(feature = "chrono-tz")]
+        ZonePolicy::Explicit(timezone) => calculate(
+            &from_datetime.with_timezone(&timezone),
+            &to_datetime.with_timezone(&timezone),
+        ),
+    }
+}
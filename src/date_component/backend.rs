@@ -0,0 +1,47 @@
+use chrono::prelude::*;
+
+use super::disambiguation::{resolve, Disambiguation, LocalTimeError};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// A datetime with a timezone, in which `calculate` reads calendar dates and local times.
+///
+/// Implemented for chrono's `DateTime<T>`, and for `time::OffsetDateTime` and `jiff::Zoned`
+/// behind the `time` and `jiff` features.
+pub trait ZonedDateTime: Clone {
+    /// Nanoseconds since the Unix epoch.
+    fn epoch_nanos(&self) -> i128;
+
+    /// The local date and time in the timezone of this datetime.
+    fn local_datetime(&self) -> NaiveDateTime;
+
+    /// The same instant in the timezone of `other`.
+    fn to_timezone_of(&self, other: &Self) -> Self;
+
+    /// The instant of the local date and time `local` in the timezone of this datetime,
+    /// disambiguated as specified.
+    fn resolve_local(&self, local: &NaiveDateTime, disambiguation: Disambiguation) -> Result<Self, LocalTimeError>;
+}
+
+impl<T: TimeZone> ZonedDateTime for DateTime<T> {
+    fn epoch_nanos(&self) -> i128 {
+        self.timestamp() as i128 * NANOS_PER_SECOND + self.timestamp_subsec_nanos() as i128
+    }
+
+    fn local_datetime(&self) -> NaiveDateTime {
+        self.naive_local()
+    }
+
+    fn to_timezone_of(&self, other: &Self) -> Self {
+        self.with_timezone(&other.timezone())
+    }
+
+    fn resolve_local(&self, local: &NaiveDateTime, disambiguation: Disambiguation) -> Result<Self, LocalTimeError> {
+        resolve(&self.timezone(), local, disambiguation)
+    }
+}
+
+/// Whole seconds elapsed from `start` to `end`, truncated toward zero.
+pub(crate) fn seconds_between<D: ZonedDateTime>(start: &D, end: &D) -> i64 {
+    ((end.epoch_nanos() - start.epoch_nanos()) / NANOS_PER_SECOND) as i64
+}
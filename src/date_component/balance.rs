@@ -0,0 +1,62 @@
+use chrono::prelude::*;
+
+use super::disambiguation::{Disambiguation, LocalTimeError};
+use super::round::Unit;
+use super::{calculate_with_options, last_whole_day, CalculateOptions, DateComponent};
+
+/// Returns the DateComponent between `from_datetime` and `to_datetime` with `largest_unit`
+/// as its largest unit, the larger units being converted into it. e.g. 1 year 2 months
+/// becomes 14 months with `Unit::Month`. Days are counted on the local calendar between
+/// the two datetimes, hours and smaller units use the elapsed time.
+/// Local times are resolved as specified by `disambiguation`.
+pub(crate) fn calculate_balanced<T: TimeZone>(
+    from_datetime: &DateTime<T>,
+    to_datetime: &DateTime<T>,
+    largest_unit: Unit,
+    disambiguation: Disambiguation,
+) -> Result<DateComponent, LocalTimeError> {
+    let options = CalculateOptions {
+        disambiguation,
+        ..CalculateOptions::default()
+    };
+    let mut balanced = calculate_with_options(from_datetime, to_datetime, options)?;
+    match largest_unit {
+        Unit::Year => return Ok(balanced),
+        Unit::Month => {
+            balanced.month += balanced.year * 12;
+            balanced.year = 0;
+        }
+        Unit::Week | Unit::Day => {
+            let to_datetime = to_datetime.with_timezone(&from_datetime.timezone());
+            let (start, end) = if balanced.invert {
+                (to_datetime, from_datetime.clone())
+            } else {
+                (from_datetime.clone(), to_datetime)
+            };
+            let (end_date, _) = last_whole_day(&start, &end, disambiguation)?;
+            balanced.year = 0;
+            balanced.month = 0;
+            balanced.day = end_date.signed_duration_since(start.naive_local().date()).num_days() as isize;
+        }
+        Unit::Hour | Unit::Minute | Unit::Second => {
+            let seconds = balanced.interval_seconds;
+            balanced.year = 0;
+            balanced.month = 0;
+            balanced.day = 0;
+            balanced.hour = seconds / 3600;
+            balanced.minute = (seconds % 3600) / 60;
+            balanced.second = seconds % 60;
+            if largest_unit != Unit::Hour {
+                balanced.minute += balanced.hour * 60;
+                balanced.hour = 0;
+            }
+            if largest_unit == Unit::Second {
+                balanced.second += balanced.minute * 60;
+                balanced.minute = 0;
+            }
+        }
+    }
+    balanced.week = balanced.day / 7;
+    balanced.modulo_days = balanced.day % 7;
+    Ok(balanced)
+}
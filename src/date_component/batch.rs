@@ -0,0 +1,205 @@
+use alloc::vec::Vec;
+use chrono::prelude::*;
+use core::iter::FromIterator;
+
+#[cfg(all(feature = "chrono-tz", feature = "std"))]
+use chrono_tz::Tz;
+#[cfg(all(feature = "chrono-tz", feature = "std"))]
+use core::any::Any;
+
+#[cfg(all(feature = "chrono-tz", feature = "std"))]
+use super::zone_cache::CachedTz;
+use super::{calculate, DateComponent};
+
+/// Batches of at least this many rows compile the offsets of their chrono-tz timezones,
+/// which takes about as long as the lookups it saves on this many rows.
+#[cfg(all(feature = "chrono-tz", feature = "std"))]
+const COMPILE_ZONE_ROWS: usize = 1 << 18;
+
+/// DateComponents stored as one column per field, e.g. to be loaded into a dataframe.
+/// The columns are `i64` so that every `isize` field fits.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DateComponentColumns {
+    pub year: Vec<i64>,
+    pub month: Vec<i64>,
+    pub week: Vec<i64>,
+    pub modulo_days: Vec<i64>,
+    pub day: Vec<i64>,
+    pub hour: Vec<i64>,
+    pub minute: Vec<i64>,
+    pub second: Vec<i64>,
+    pub interval_seconds: Vec<i64>,
+    pub interval_minutes: Vec<i64>,
+    pub interval_hours: Vec<i64>,
+    pub interval_days: Vec<i64>,
+    pub invert: Vec<bool>,
+}
+
+impl DateComponentColumns {
+    /// Returns empty columns with room for `capacity` rows.
+    pub fn with_capacity(capacity: usize) -> Self {
+        DateComponentColumns {
+            year: Vec::with_capacity(capacity),
+            month: Vec::with_capacity(capacity),
+            week: Vec::with_capacity(capacity),
+            modulo_days: Vec::with_capacity(capacity),
+            day: Vec::with_capacity(capacity),
+            hour: Vec::with_capacity(capacity),
+            minute: Vec::with_capacity(capacity),
+            second: Vec::with_capacity(capacity),
+            interval_seconds: Vec::with_capacity(capacity),
+            interval_minutes: Vec::with_capacity(capacity),
+            interval_hours: Vec::with_capacity(capacity),
+            interval_days: Vec::with_capacity(capacity),
+            invert: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Number of rows.
+    pub fn len(&self) -> usize {
+        self.invert.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.invert.is_empty()
+    }
+
+    /// Appends a DateComponent as a new row.
+    pub fn push(&mut self, component: &DateComponent) {
+        self.year.push(component.year as i64);
+        self.month.push(component.month as i64);
+        self.week.push(component.week as i64);
+        self.modulo_days.push(component.modulo_days as i64);
+        self.day.push(component.day as i64);
+        self.hour.push(component.hour as i64);
+        self.minute.push(component.minute as i64);
+        self.second.push(component.second as i64);
+        self.interval_seconds.push(component.interval_seconds as i64);
+        self.interval_minutes.push(component.interval_minutes as i64);
+        self.interval_hours.push(component.interval_hours as i64);
+        self.interval_days.push(component.interval_days as i64);
+        self.invert.push(component.invert);
+    }
+
+    /// Returns the row at `index` as a DateComponent, or `None` if out of range.
+    pub fn get(&self, index: usize) -> Option<DateComponent> {
+        if index >= self.len() {
+            return None;
+        }
+        Some(DateComponent {
+            year: self.year[index] as isize,
+            month: self.month[index] as isize,
+            week: self.week[index] as isize,
+            modulo_days: self.modulo_days[index] as isize,
+            day: self.day[index] as isize,
+            hour: self.hour[index] as isize,
+            minute: self.minute[index] as isize,
+            second: self.second[index] as isize,
+            interval_seconds: self.interval_seconds[index] as isize,
+            interval_minutes: self.interval_minutes[index] as isize,
+            interval_hours: self.interval_hours[index] as isize,
+            interval_days: self.interval_days[index] as isize,
+            invert: self.invert[index],
+        })
+    }
+}
+
+impl Extend<DateComponent> for DateComponentColumns {
+    fn extend<I: IntoIterator<Item = DateComponent>>(&mut self, iter: I) {
+        for component in iter {
+            self.push(&component);
+        }
+    }
+}
+
+impl FromIterator<DateComponent> for DateComponentColumns {
+    fn from_iter<I: IntoIterator<Item = DateComponent>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut columns = DateComponentColumns::with_capacity(iter.size_hint().0);
+        columns.extend(iter);
+        columns
+    }
+}
+
+/// Timezone state shared by the rows of one batch.
+/// Rows in a chrono-tz timezone are calculated in its `CachedTz`, which gives the same
+/// DateComponents, when the offsets of the timezone are compiled already
+/// or the batch is large enough to pay for compiling them.
+struct BatchZones {
+    #[cfg(all(feature = "chrono-tz", feature = "std"))]
+    compile: bool,
+    /// Timezone of the previous row and its `CachedTz`, if any.
+    #[cfg(all(feature = "chrono-tz", feature = "std"))]
+    zone: Option<(Tz, Option<CachedTz>)>,
+}
+
+impl BatchZones {
+    #[cfg_attr(not(all(feature = "chrono-tz", feature = "std")), allow(unused_variables))]
+    fn new(rows: usize) -> Self {
+        BatchZones {
+            #[cfg(all(feature = "chrono-tz", feature = "std"))]
+            compile: rows >= COMPILE_ZONE_ROWS,
+            #[cfg(all(feature = "chrono-tz", feature = "std"))]
+            zone: None,
+        }
+    }
+
+    fn calculate<T: TimeZone + 'static>(&mut self, from: &DateTime<T>, to: &DateTime<T>) -> DateComponent {
+        #[cfg(all(feature = "chrono-tz", feature = "std"))]
+        if let (Some(from), Some(to)) = (
+            (from as &dyn Any).downcast_ref::<DateTime<Tz>>(),
+            (to as &dyn Any).downcast_ref::<DateTime<Tz>>(),
+        ) {
+            if let Some(cached) = self.cached(from.timezone()) {
+                return calculate(&from.with_timezone(&cached), &to.with_timezone(&cached));
+            }
+        }
+        calculate(from, to)
+    }
+
+    #[cfg(all(feature = "chrono-tz", feature = "std"))]
+    fn cached(&mut self, tz: Tz) -> Option<CachedTz> {
+        match self.zone {
+            Some((zone, cached)) if zone == tz => cached,
+            _ => {
+                let cached = if self.compile {
+                    Some(CachedTz::new(tz))
+                } else {
+                    CachedTz::compiled(tz)
+                };
+                self.zone = Some((tz, cached));
+                cached
+            }
+        }
+    }
+}
+
+/// Returns the DateComponents between each pair of from and to datetimes, in input order.
+/// Each pair is calculated as by `calculate`, in the timezone of its from datetime.
+/// With the `chrono-tz` feature, rows in a `Tz` share the offset table of its `CachedTz`
+/// when it is compiled already, e.g. by `CachedTz::new`, or the batch has at least 262,144 rows.
+///
+/// Panics if the slices have different lengths.
+pub fn calculate_many<T: TimeZone + 'static>(from_datetimes: &[DateTime<T>], to_datetimes: &[DateTime<T>]) -> Vec<DateComponent> {
+    assert_eq!(from_datetimes.len(), to_datetimes.len(), "from and to must have the same length");
+    let mut zones = BatchZones::new(from_datetimes.len());
+    from_datetimes
+        .iter()
+        .zip(to_datetimes)
+        .map(|(from, to)| zones.calculate(from, to))
+        .collect()
+}
+
+/// Same as `calculate_many`, but writes the fields straight into one column each,
+/// allocated once for all rows, instead of a Vec of DateComponents.
+///
+/// Panics if the slices have different lengths.
+pub fn calculate_columns<T: TimeZone + 'static>(from_datetimes: &[DateTime<T>], to_datetimes: &[DateTime<T>]) -> DateComponentColumns {
+    assert_eq!(from_datetimes.len(), to_datetimes.len(), "from and to must have the same length");
+    let mut zones = BatchZones::new(from_datetimes.len());
+    let mut columns = DateComponentColumns::with_capacity(from_datetimes.len());
+    for (from, to) in from_datetimes.iter().zip(to_datetimes) {
+        columns.push(&zones.calculate(from, to));
+    }
+    columns
+}
@@ -0,0 +1,72 @@
+use chrono::prelude::*;
+use core::cmp::Ordering;
+
+use super::disambiguation::{Disambiguation, LocalTimeError};
+use super::shift::apply;
+use super::DateComponent;
+
+/// Compares two DateComponents by the date they reach when applied to `relative_to`.
+/// e.g. "1 month" is longer than "30 days" from January, but shorter from February.
+/// Local times are resolved as specified by `disambiguation`.
+pub fn compare<T: TimeZone>(
+    a: &DateComponent,
+    b: &DateComponent,
+    relative_to: &DateTime<T>,
+    disambiguation: Disambiguation,
+) -> Result<Ordering, LocalTimeError> {
+    let a = apply(relative_to, a, disambiguation)?;
+    let b = apply(relative_to, b, disambiguation)?;
+    Ok(a.cmp(&b))
+}
+
+/// A DateComponent attached to the date it starts from, which gives it a total order.
+/// Can be sorted or used as a `BTreeMap` key.
+/// Values are ordered by elapsed time, then by anchor, so equal lengths from different anchors
+/// are distinct keys.
+/// Local times are resolved with `Disambiguation::Compatible`, which always succeeds.
+#[derive(Debug, Clone)]
+pub struct RelativeDateComponent<T: TimeZone> {
+    /// The interval.
+    pub component: DateComponent,
+    /// Date the interval starts from.
+    pub relative_to: DateTime<T>,
+}
+
+impl<T: TimeZone> RelativeDateComponent<T> {
+    pub fn new(component: DateComponent, relative_to: DateTime<T>) -> Self {
+        RelativeDateComponent { component, relative_to }
+    }
+
+    /// Elapsed time from `relative_to` to the end of the interval, negative when inverted.
+    pub fn elapsed(&self) -> chrono::Duration {
+        end_of(&self.relative_to, &self.component).signed_duration_since(self.relative_to.clone())
+    }
+}
+
+impl<T: TimeZone> PartialEq for RelativeDateComponent<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: TimeZone> Eq for RelativeDateComponent<T> {}
+
+impl<T: TimeZone> PartialOrd for RelativeDateComponent<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: TimeZone> Ord for RelativeDateComponent<T> {
+    /// Orders by elapsed time, which is the order of the end dates when the anchors are equal,
+    /// then by anchor.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.elapsed()
+            .cmp(&other.elapsed())
+            .then_with(|| self.relative_to.cmp(&other.relative_to))
+    }
+}
+
+fn end_of<T: TimeZone>(relative_to: &DateTime<T>, component: &DateComponent) -> DateTime<T> {
+    apply(relative_to, component, Disambiguation::Compatible).expect("compatible disambiguation always resolves")
+}
@@ -0,0 +1,71 @@
+use chrono::prelude::*;
+use chrono::Duration;
+use core::fmt;
+
+/// How to choose an instant for a local time that happens twice (when clocks go back)
+/// or never (when clocks go forward) in a timezone.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Disambiguation {
+    /// The earlier instant. A time in a gap is moved backward by the length of the gap.
+    Earlier,
+    /// The later instant. A time in a gap is moved forward by the length of the gap.
+    Later,
+    /// The earlier instant, and a time in a gap is moved forward by the length of the gap.
+    #[default]
+    Compatible,
+    /// Fail with a `LocalTimeError`.
+    Reject,
+}
+
+/// Error returned when a local time cannot be resolved with `Disambiguation::Reject`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LocalTimeError {
+    /// The local time happens twice.
+    Ambiguous(NaiveDateTime),
+    /// The local time does not exist.
+    Nonexistent(NaiveDateTime),
+}
+
+impl fmt::Display for LocalTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LocalTimeError::Ambiguous(local) => write!(f, "local time {} is ambiguous", local),
+            LocalTimeError::Nonexistent(local) => write!(f, "local time {} does not exist", local),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LocalTimeError {}
+
+/// Returns the instant of `local` in `timezone`, disambiguated as specified.
+pub(crate) fn resolve<T: TimeZone>(
+    timezone: &T,
+    local: &NaiveDateTime,
+    disambiguation: Disambiguation,
+) -> Result<DateTime<T>, LocalTimeError> {
+    match timezone.from_local_datetime(local) {
+        chrono::LocalResult::Single(d) => Ok(d),
+        chrono::LocalResult::Ambiguous(earlier, later) => match disambiguation {
+            Disambiguation::Earlier | Disambiguation::Compatible => Ok(earlier),
+            Disambiguation::Later => Ok(later),
+            Disambiguation::Reject => Err(LocalTimeError::Ambiguous(*local)),
+        },
+        chrono::LocalResult::None => {
+            // Reading the local time with the offset after the gap gives an instant
+            // before it, and with the offset before the gap an instant after it.
+            let offset = match disambiguation {
+                Disambiguation::Earlier => offset_around(timezone, local, 1),
+                Disambiguation::Later | Disambiguation::Compatible => offset_around(timezone, local, -1),
+                Disambiguation::Reject => return Err(LocalTimeError::Nonexistent(*local)),
+            };
+            Ok(timezone.from_utc_datetime(&(*local - Duration::seconds(offset))))
+        }
+    }
+}
+
+/// Offset in seconds one day before (`direction` -1) or after (`direction` 1) `local`.
+fn offset_around<T: TimeZone>(timezone: &T, local: &NaiveDateTime, direction: i64) -> i64 {
+    let utc = *local + Duration::days(direction);
+    timezone.offset_from_utc_datetime(&utc).fix().local_minus_utc() as i64
+}
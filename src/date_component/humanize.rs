@@ -0,0 +1,35 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use super::DateComponent;
+
+impl DateComponent {
+    /// Returns the DateComponent in words, e.g. `1 year, 2 months and 3 days`,
+    /// followed by `ago` when inverted. Zero units are left out.
+    pub fn humanize(&self) -> String {
+        let units = [
+            (self.year, "year"),
+            (self.month, "month"),
+            (self.day, "day"),
+            (self.hour, "hour"),
+            (self.minute, "minute"),
+            (self.second, "second"),
+        ];
+        let parts: Vec<String> = units
+            .iter()
+            .filter(|(value, _)| *value != 0)
+            .map(|(value, unit)| format!("{} {}{}", value, unit, if *value == 1 { "" } else { "s" }))
+            .collect();
+        let text = match parts.split_last() {
+            None => "0 seconds".to_string(),
+            Some((last, [])) => last.clone(),
+            Some((last, rest)) => format!("{} and {}", rest.join(", "), last),
+        };
+        if self.invert {
+            format!("{} ago", text)
+        } else {
+            text
+        }
+    }
+}
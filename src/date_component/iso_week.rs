@@ -0,0 +1,74 @@
+use alloc::string::{String, ToString};
+use chrono::prelude::*;
+use chrono::IsoWeek;
+
+use super::backend::{seconds_between, ZonedDateTime};
+
+/// Difference between two datetimes in ISO 8601 weeks, see `calculate_iso_weeks`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IsoWeekComponent {
+    /// Number of ISO week boundaries (Mondays) crossed between the local dates.
+    pub weeks: isize,
+    /// Difference between the ISO week-years of the local dates.
+    pub week_years: isize,
+    /// Local date of the from datetime.
+    pub from_date: NaiveDate,
+    /// Local date of the to datetime, in the timezone of the from datetime.
+    pub to_date: NaiveDate,
+    /// Is true if the interval represents a negative time period and false otherwise
+    pub invert: bool,
+}
+
+impl IsoWeekComponent {
+    /// ISO week of the from datetime.
+    pub fn from_week(&self) -> IsoWeek {
+        self.from_date.iso_week()
+    }
+
+    /// ISO week of the to datetime.
+    pub fn to_week(&self) -> IsoWeek {
+        self.to_date.iso_week()
+    }
+
+    /// ISO week date of the from datetime, e.g. `2024-W05-3`.
+    pub fn from_week_date(&self) -> String {
+        week_date(&self.from_date)
+    }
+
+    /// ISO week date of the to datetime, e.g. `2024-W05-3`.
+    pub fn to_week_date(&self) -> String {
+        week_date(&self.to_date)
+    }
+}
+
+/// Returns the number of ISO weeks and week-years between the from and to datetime,
+/// counted on the local dates in the timezone of the from datetime.
+/// e.g. from Sunday 2024-02-04 (2024-W05-7) to Monday 2024-02-05 (2024-W06-1)
+/// one week boundary is crossed, although `calculate` finds 1 day.
+/// Years have 52 or 53 ISO weeks, and the week-year of the first and last days of a
+/// calendar year may differ from the calendar year (2024-12-30 is 2025-W01-1).
+pub fn calculate_iso_weeks<D: ZonedDateTime>(from_datetime: &D, to_datetime: &D) -> IsoWeekComponent {
+    let from_date = from_datetime.local_datetime().date();
+    let to_date = to_datetime.to_timezone_of(from_datetime).local_datetime().date();
+    let invert = seconds_between(from_datetime, to_datetime) < 0;
+
+    let weeks = week_start(&to_date).signed_duration_since(week_start(&from_date)).num_days() / 7;
+    let week_years = to_date.iso_week().year() as i64 - from_date.iso_week().year() as i64;
+    IsoWeekComponent {
+        weeks: weeks.abs() as isize,
+        week_years: week_years.abs() as isize,
+        from_date,
+        to_date,
+        invert,
+    }
+}
+
+/// Monday of the ISO week of `date`.
+fn week_start(date: &NaiveDate) -> NaiveDate {
+    let week = date.iso_week();
+    NaiveDate::from_isoywd_opt(week.year(), week.week(), Weekday::Mon).expect("date out of range")
+}
+
+fn week_date(date: &NaiveDate) -> String {
+    date.format("%G-W%V-%u").to_string()
+}
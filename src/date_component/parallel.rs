@@ -0,0 +1,28 @@
+use chrono::prelude::*;
+use rayon::prelude::*;
+
+use super::{calculate, DateComponent};
+
+/// Returns the DateComponents between each pair of from and to datetimes, in input order,
+/// using all cores. Same results as `calculate_many`.
+///
+/// Panics if the slices have different lengths.
+pub fn par_calculate<T>(from_datetimes: &[DateTime<T>], to_datetimes: &[DateTime<T>]) -> Vec<DateComponent>
+where
+    T: TimeZone + Sync,
+    T::Offset: Sync,
+{
+    assert_eq!(from_datetimes.len(), to_datetimes.len(), "from and to must have the same length");
+    par_calculate_pairs(from_datetimes.par_iter().zip(to_datetimes.par_iter()))
+}
+
+/// Returns the DateComponents between each pair of from and to datetimes of a parallel iterator,
+/// in input order.
+pub fn par_calculate_pairs<'a, T, I>(pairs: I) -> Vec<DateComponent>
+where
+    T: TimeZone + Sync + 'a,
+    T::Offset: Sync,
+    I: IndexedParallelIterator<Item = (&'a DateTime<T>, &'a DateTime<T>)>,
+{
+    pairs.map(|(from, to)| calculate(from, to)).collect()
+}
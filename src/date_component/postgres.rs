@@ -0,0 +1,631 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use super::DateComponent;
+
+const MICROS_PER_SECOND: i128 = 1_000_000;
+const MICROS_PER_MINUTE: i128 = 60 * MICROS_PER_SECOND;
+const MICROS_PER_HOUR: i128 = 60 * MICROS_PER_MINUTE;
+const SECONDS_PER_DAY: i64 = 86_400;
+/// PostgreSQL counts a month as 30 days and a year as 365.25 days when it needs a length.
+const SECONDS_PER_MONTH: i64 = 30 * SECONDS_PER_DAY;
+const SECONDS_PER_YEAR: i64 = 31_557_600;
+
+/// A PostgreSQL `interval`, stored as months, days and microseconds which are
+/// independent of each other: a month is not a fixed number of days, and a day
+/// is not always 24 hours. Each part may have its own sign.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+#[cfg_attr(
+    feature = "diesel",
+    diesel(sql_type = diesel::sql_types::Interval, sql_type = diesel::sql_types::Text)
+)]
+pub struct PostgresInterval {
+    /// Number of months, including years as 12 months.
+    pub months: i32,
+    /// Number of days.
+    pub days: i32,
+    /// Number of microseconds, including hours, minutes and seconds.
+    pub microseconds: i64,
+}
+
+/// Text output styles of PostgreSQL intervals, as set by `SET intervalstyle`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum IntervalStyle {
+    /// `1 year 2 mons 3 days 04:05:06`
+    #[default]
+    Postgres,
+    /// `@ 1 year 2 mons 3 days 4 hours 5 mins 6 secs`
+    PostgresVerbose,
+    /// `+1-2 +3 +4:05:06`
+    SqlStandard,
+    /// `P1Y2M3DT4H5M6S`
+    Iso8601,
+}
+
+/// Error returned when converting or parsing a `PostgresInterval` fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PostgresIntervalError {
+    /// The months, days and time have different signs, which a DateComponent cannot represent.
+    MixedSigns,
+    /// A value does not fit in the fields of an interval.
+    OutOfRange,
+    /// The text is not an interval in the expected style.
+    InvalidFormat(String),
+}
+
+impl fmt::Display for PostgresIntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PostgresIntervalError::MixedSigns => write!(f, "interval parts have different signs"),
+            PostgresIntervalError::OutOfRange => write!(f, "interval out of range"),
+            PostgresIntervalError::InvalidFormat(text) => write!(f, "invalid interval {:?}", text),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PostgresIntervalError {}
+
+impl PostgresInterval {
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        PostgresInterval {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
+    /// Parses `text` as printed by PostgreSQL in the given `style`.
+    pub fn parse(text: &str, style: IntervalStyle) -> Result<Self, PostgresIntervalError> {
+        let parts = match style {
+            IntervalStyle::Postgres => parse_postgres(text),
+            IntervalStyle::PostgresVerbose => parse_verbose(text),
+            IntervalStyle::SqlStandard => parse_sql_standard(text),
+            IntervalStyle::Iso8601 => parse_iso8601(text),
+        };
+        parts
+            .ok_or_else(|| PostgresIntervalError::InvalidFormat(text.to_string()))?
+            .into_interval()
+    }
+
+    /// Returns the text PostgreSQL prints for this interval in the given `style`.
+    pub fn format(&self, style: IntervalStyle) -> String {
+        let fields = Fields::from(self);
+        match style {
+            IntervalStyle::Postgres => format_postgres(&fields),
+            IntervalStyle::PostgresVerbose => format_verbose(&fields),
+            IntervalStyle::SqlStandard => format_sql_standard(&fields),
+            IntervalStyle::Iso8601 => format_iso8601(&fields),
+        }
+    }
+}
+
+impl fmt::Display for PostgresInterval {
+    /// Formats the interval in the default `postgres` style.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(IntervalStyle::Postgres))
+    }
+}
+
+impl FromStr for PostgresInterval {
+    type Err = PostgresIntervalError;
+
+    /// Parses an interval in any of the output styles, detected from the text.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let trimmed = text.trim_start();
+        let style = if trimmed.starts_with('@') {
+            IntervalStyle::PostgresVerbose
+        } else if trimmed.starts_with('P') {
+            IntervalStyle::Iso8601
+        } else if trimmed.chars().any(|c| c.is_ascii_alphabetic()) {
+            IntervalStyle::Postgres
+        } else {
+            IntervalStyle::SqlStandard
+        };
+        PostgresInterval::parse(text, style)
+    }
+}
+
+impl TryFrom<DateComponent> for PostgresInterval {
+    type Error = PostgresIntervalError;
+
+    /// Years are counted as 12 months and hours, minutes and seconds as microseconds.
+    /// All parts are negative when the DateComponent is inverted.
+    fn try_from(component: DateComponent) -> Result<Self, Self::Error> {
+        let sign = if component.invert { -1 } else { 1 };
+        let seconds = component.hour as i128 * 3600 + component.minute as i128 * 60 + component.second as i128;
+        Parts {
+            months: sign * (component.year as i128 * 12 + component.month as i128),
+            days: sign * component.day as i128,
+            microseconds: sign * seconds * MICROS_PER_SECOND,
+        }
+        .into_interval()
+    }
+}
+
+impl TryFrom<PostgresInterval> for DateComponent {
+    type Error = PostgresIntervalError;
+
+    /// Fails with `MixedSigns` unless all parts of the interval have the same sign.
+    /// Fractions of a second are dropped. Without dates to measure between, the
+    /// `interval_*` totals count a month as 30 days and a year as 365.25 days,
+    /// as `EXTRACT(EPOCH FROM interval)` does.
+    fn try_from(interval: PostgresInterval) -> Result<Self, Self::Error> {
+        let negative = interval.months < 0 || interval.days < 0 || interval.microseconds < 0;
+        let positive = interval.months > 0 || interval.days > 0 || interval.microseconds > 0;
+        if negative && positive {
+            return Err(PostgresIntervalError::MixedSigns);
+        }
+
+        let months = (interval.months as i64).abs();
+        let days = (interval.days as i64).abs();
+        let seconds = (interval.microseconds.unsigned_abs() / MICROS_PER_SECOND as u64) as i64;
+        let interval_seconds =
+            months / 12 * SECONDS_PER_YEAR + months % 12 * SECONDS_PER_MONTH + days * SECONDS_PER_DAY + seconds;
+
+        Ok(DateComponent {
+            year: (months / 12) as isize,
+            month: (months % 12) as isize,
+            week: (days / 7) as isize,
+            modulo_days: (days % 7) as isize,
+            day: days as isize,
+            hour: (seconds / 3600) as isize,
+            minute: (seconds % 3600 / 60) as isize,
+            second: (seconds % 60) as isize,
+            interval_seconds: interval_seconds as isize,
+            interval_minutes: (interval_seconds / 60) as isize,
+            interval_hours: (interval_seconds / 3600) as isize,
+            interval_days: (interval_seconds / SECONDS_PER_DAY) as isize,
+            invert: negative,
+        })
+    }
+}
+
+/// Fields of an interval as PostgreSQL prints them. The year and month have the
+/// sign of the months, and the hour to fraction the sign of the microseconds.
+struct Fields {
+    year: i64,
+    month: i64,
+    day: i64,
+    hour: i64,
+    minute: i64,
+    second: i64,
+    fraction: i64,
+}
+
+impl Fields {
+    fn from(interval: &PostgresInterval) -> Self {
+        let micros = interval.microseconds as i128;
+        Fields {
+            year: interval.months as i64 / 12,
+            month: interval.months as i64 % 12,
+            day: interval.days as i64,
+            hour: (micros / MICROS_PER_HOUR) as i64,
+            minute: (micros / MICROS_PER_MINUTE % 60) as i64,
+            second: (micros / MICROS_PER_SECOND % 60) as i64,
+            fraction: (micros % MICROS_PER_SECOND) as i64,
+        }
+    }
+
+    fn has_time(&self) -> bool {
+        self.hour != 0 || self.minute != 0 || self.second != 0 || self.fraction != 0
+    }
+
+    fn is_time_negative(&self) -> bool {
+        self.hour < 0 || self.minute < 0 || self.second < 0 || self.fraction < 0
+    }
+
+    fn is_zero(&self) -> bool {
+        self.year == 0 && self.month == 0 && self.day == 0 && !self.has_time()
+    }
+}
+
+/// Returns the absolute seconds with the fraction, without trailing zeros.
+fn seconds(second: i64, fraction: i64, zero_padded: bool) -> String {
+    let mut text = if zero_padded {
+        format!("{:02}", second.abs())
+    } else {
+        second.abs().to_string()
+    };
+    if fraction != 0 {
+        text.push('.');
+        text.push_str(format!("{:06}", fraction.abs()).trim_end_matches('0'));
+    }
+    text
+}
+
+fn plural(is_plural: bool) -> &'static str {
+    if is_plural {
+        "s"
+    } else {
+        ""
+    }
+}
+
+fn format_postgres(fields: &Fields) -> String {
+    let mut text = String::new();
+    // A positive part after a negative one gets an explicit sign.
+    let mut is_before = false;
+    for &(value, unit) in &[(fields.year, "year"), (fields.month, "mon"), (fields.day, "day")] {
+        if value == 0 {
+            continue;
+        }
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        let sign = if is_before && value > 0 { "+" } else { "" };
+        text.push_str(&format!("{}{} {}{}", sign, value, unit, plural(value != 1)));
+        is_before = value < 0;
+    }
+    if text.is_empty() || fields.has_time() {
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        let sign = if fields.is_time_negative() {
+            "-"
+        } else if is_before {
+            "+"
+        } else {
+            ""
+        };
+        text.push_str(&format!(
+            "{}{:02}:{:02}:{}",
+            sign,
+            fields.hour.abs(),
+            fields.minute.abs(),
+            seconds(fields.second, fields.fraction, true)
+        ));
+    }
+    text
+}
+
+fn format_verbose(fields: &Fields) -> String {
+    let mut text = String::from("@");
+    // The sign of the first part is written as "ago", the other parts are relative to it.
+    let mut is_zero = true;
+    let mut is_before = false;
+    let parts = [
+        (fields.year, "year"),
+        (fields.month, "mon"),
+        (fields.day, "day"),
+        (fields.hour, "hour"),
+        (fields.minute, "min"),
+    ];
+    for &(value, unit) in &parts {
+        if value == 0 {
+            continue;
+        }
+        let value = if is_zero {
+            is_before = value < 0;
+            value.abs()
+        } else if is_before {
+            -value
+        } else {
+            value
+        };
+        text.push_str(&format!(" {} {}{}", value, unit, plural(value != 1)));
+        is_zero = false;
+    }
+    if fields.second != 0 || fields.fraction != 0 {
+        let negative = fields.second < 0 || fields.fraction < 0;
+        text.push(' ');
+        if is_zero {
+            is_before = negative;
+        } else if negative != is_before {
+            text.push('-');
+        }
+        text.push_str(&seconds(fields.second, fields.fraction, false));
+        let is_plural = fields.second.abs() != 1 || fields.fraction != 0;
+        text.push_str(&format!(" sec{}", plural(is_plural)));
+        is_zero = false;
+    }
+    if is_zero {
+        text.push_str(" 0");
+    }
+    if is_before {
+        text.push_str(" ago");
+    }
+    text
+}
+
+fn format_sql_standard(fields: &Fields) -> String {
+    let values = [
+        fields.year,
+        fields.month,
+        fields.day,
+        fields.hour,
+        fields.minute,
+        fields.second,
+        fields.fraction,
+    ];
+    let has_negative = values.iter().any(|v| *v < 0);
+    let has_positive = values.iter().any(|v| *v > 0);
+    let has_year_month = fields.year != 0 || fields.month != 0;
+    let has_day_time = fields.day != 0 || fields.has_time();
+    let time = format!(
+        "{}:{:02}:{}",
+        fields.hour.abs(),
+        fields.minute.abs(),
+        seconds(fields.second, fields.fraction, true)
+    );
+
+    if !has_negative && !has_positive {
+        return "0".to_string();
+    }
+    // Values outside the SQL standard are written with a sign on every part.
+    if (has_negative && has_positive) || (has_year_month && has_day_time) {
+        let sign = |negative: bool| if negative { '-' } else { '+' };
+        return format!(
+            "{}{}-{} {}{} {}{}",
+            sign(fields.year < 0 || fields.month < 0),
+            fields.year.abs(),
+            fields.month.abs(),
+            sign(fields.day < 0),
+            fields.day.abs(),
+            sign(fields.is_time_negative()),
+            time
+        );
+    }
+    let sign = if has_negative { "-" } else { "" };
+    if has_year_month {
+        format!("{}{}-{}", sign, fields.year.abs(), fields.month.abs())
+    } else if fields.day != 0 {
+        format!("{}{} {}", sign, fields.day.abs(), time)
+    } else {
+        format!("{}{}", sign, time)
+    }
+}
+
+fn format_iso8601(fields: &Fields) -> String {
+    if fields.is_zero() {
+        return "PT0S".to_string();
+    }
+    let mut text = String::from("P");
+    for &(value, designator) in &[(fields.year, 'Y'), (fields.month, 'M'), (fields.day, 'D')] {
+        if value != 0 {
+            text.push_str(&format!("{}{}", value, designator));
+        }
+    }
+    if fields.has_time() {
+        text.push('T');
+        for &(value, designator) in &[(fields.hour, 'H'), (fields.minute, 'M')] {
+            if value != 0 {
+                text.push_str(&format!("{}{}", value, designator));
+            }
+        }
+        if fields.second != 0 || fields.fraction != 0 {
+            if fields.second < 0 || fields.fraction < 0 {
+                text.push('-');
+            }
+            text.push_str(&seconds(fields.second, fields.fraction, false));
+            text.push('S');
+        }
+    }
+    text
+}
+
+/// Parts of an interval being parsed, wide enough that adding parts cannot overflow.
+#[derive(Default)]
+struct Parts {
+    months: i128,
+    days: i128,
+    microseconds: i128,
+}
+
+impl Parts {
+    fn into_interval(self) -> Result<PostgresInterval, PostgresIntervalError> {
+        let out_of_range = |_| PostgresIntervalError::OutOfRange;
+        Ok(PostgresInterval {
+            months: i32::try_from(self.months).map_err(out_of_range)?,
+            days: i32::try_from(self.days).map_err(out_of_range)?,
+            microseconds: i64::try_from(self.microseconds).map_err(out_of_range)?,
+        })
+    }
+
+    fn negate(&mut self) {
+        self.months = -self.months;
+        self.days = -self.days;
+        self.microseconds = -self.microseconds;
+    }
+
+    /// Adds `number` of `unit`, e.g. `3` and `days`.
+    fn add_unit(&mut self, number: &str, unit: &str) -> Option<()> {
+        match unit {
+            "year" | "years" => self.months += 12 * parse_integer(number)?,
+            "mon" | "mons" | "month" | "months" => self.months += parse_integer(number)?,
+            "week" | "weeks" => self.days += 7 * parse_integer(number)?,
+            "day" | "days" => self.days += parse_integer(number)?,
+            "hour" | "hours" => self.microseconds += MICROS_PER_HOUR * parse_integer(number)?,
+            "min" | "mins" | "minute" | "minutes" => self.microseconds += MICROS_PER_MINUTE * parse_integer(number)?,
+            "sec" | "secs" | "second" | "seconds" => self.microseconds += parse_seconds(number)?,
+            _ => return None,
+        }
+        Some(())
+    }
+}
+
+/// Splits a leading `+` or `-` from `text`, returning -1 for a minus and 1 otherwise.
+fn split_sign(text: &str) -> (i128, &str) {
+    if let Some(rest) = text.strip_prefix('-') {
+        (-1, rest)
+    } else {
+        (1, text.strip_prefix('+').unwrap_or(text))
+    }
+}
+
+fn parse_digits(text: &str) -> Option<i128> {
+    if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    text.parse::<i64>().ok().map(i128::from)
+}
+
+fn parse_integer(text: &str) -> Option<i128> {
+    let (sign, digits) = split_sign(text);
+    Some(sign * parse_digits(digits)?)
+}
+
+/// Parses signed seconds with up to 6 fractional digits as microseconds.
+fn parse_seconds(text: &str) -> Option<i128> {
+    let (sign, text) = split_sign(text);
+    let (whole, fraction) = match text.find('.') {
+        Some(index) => (&text[..index], &text[index + 1..]),
+        None => (text, "0"),
+    };
+    if fraction.len() > 6 {
+        return None;
+    }
+    let fraction = parse_digits(fraction)? * 10i128.pow(6 - fraction.len() as u32);
+    Some(sign * (parse_digits(whole)? * MICROS_PER_SECOND + fraction))
+}
+
+/// Parses a signed `H:MM:SS.ffffff` or `H:MM` time as microseconds.
+fn parse_time(text: &str) -> Option<i128> {
+    let (sign, text) = split_sign(text);
+    let mut fields = text.split(':');
+    let hours = parse_digits(fields.next()?)?;
+    let minutes = parse_digits(fields.next()?)?;
+    let seconds = match fields.next() {
+        Some(seconds) => parse_seconds(seconds).filter(|s| *s >= 0)?,
+        None => 0,
+    };
+    if fields.next().is_some() || minutes >= 60 || seconds >= 60 * MICROS_PER_SECOND {
+        return None;
+    }
+    Some(sign * (hours * MICROS_PER_HOUR + minutes * MICROS_PER_MINUTE + seconds))
+}
+
+/// `1 year 2 mons -3 days +04:05:06`
+fn parse_postgres(text: &str) -> Option<Parts> {
+    let mut parts = Parts::default();
+    let mut tokens = text.split_whitespace();
+    let mut is_empty = true;
+    while let Some(token) = tokens.next() {
+        if token.contains(':') {
+            parts.microseconds += parse_time(token)?;
+        } else {
+            parts.add_unit(token, tokens.next()?)?;
+        }
+        is_empty = false;
+    }
+    if is_empty {
+        return None;
+    }
+    Some(parts)
+}
+
+/// `@ 1 year 2 mons -3 days 4 hours 5 mins 6 secs ago`
+fn parse_verbose(text: &str) -> Option<Parts> {
+    let mut tokens: Vec<&str> = text.trim_start().strip_prefix('@')?.split_whitespace().collect();
+    let ago = tokens.last() == Some(&"ago");
+    if ago {
+        tokens.pop();
+    }
+    let mut parts = Parts::default();
+    if tokens == ["0"] {
+        return Some(parts);
+    }
+    if tokens.is_empty() || !tokens.len().is_multiple_of(2) {
+        return None;
+    }
+    for pair in tokens.chunks(2) {
+        parts.add_unit(pair[0], pair[1])?;
+    }
+    if ago {
+        parts.negate();
+    }
+    Some(parts)
+}
+
+/// `+1-2 -3 +4:05:06`, or `-1-2` where a leading minus applies to every part without a sign.
+fn parse_sql_standard(text: &str) -> Option<Parts> {
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    let mut parts = Parts::default();
+    match tokens.as_slice() {
+        [] => return None,
+        // A lone number is a number of seconds.
+        [seconds] if !seconds.contains(':') && !split_sign(seconds).1.contains('-') => {
+            parts.microseconds = parse_seconds(seconds)?;
+            return Some(parts);
+        }
+        _ => {}
+    }
+
+    let has_sign = |token: &&str| token.starts_with('-') || token.starts_with('+');
+    let negate_all = tokens[0].starts_with('-') && !tokens[1..].iter().any(has_sign);
+    for token in &tokens {
+        let (sign, body) = split_sign(token);
+        let sign = if negate_all { -1 } else { sign };
+        if body.contains(':') {
+            parts.microseconds += sign * parse_time(body)?;
+        } else if let Some(index) = body.find('-') {
+            let years = parse_digits(&body[..index])?;
+            let months = parse_digits(&body[index + 1..])?;
+            parts.months += sign * (years * 12 + months);
+        } else {
+            parts.days += sign * parse_digits(body)?;
+        }
+    }
+    Some(parts)
+}
+
+/// `P1Y2M3DT4H5M6.5S`, where every number may have a sign.
+fn parse_iso8601(text: &str) -> Option<Parts> {
+    let text = text.trim().strip_prefix('P')?;
+    let (date, time) = match text.find('T') {
+        Some(index) => (&text[..index], Some(&text[index + 1..])),
+        None => (text, None),
+    };
+    if date.is_empty() && time.is_none() {
+        return None;
+    }
+
+    let mut parts = Parts::default();
+    for (number, designator) in designators(date)? {
+        match designator {
+            'Y' => parts.months += 12 * parse_integer(number)?,
+            'M' => parts.months += parse_integer(number)?,
+            'W' => parts.days += 7 * parse_integer(number)?,
+            'D' => parts.days += parse_integer(number)?,
+            _ => return None,
+        }
+    }
+    if let Some(time) = time {
+        if time.is_empty() {
+            return None;
+        }
+        for (number, designator) in designators(time)? {
+            match designator {
+                'H' => parts.microseconds += MICROS_PER_HOUR * parse_integer(number)?,
+                'M' => parts.microseconds += MICROS_PER_MINUTE * parse_integer(number)?,
+                'S' => parts.microseconds += parse_seconds(number)?,
+                _ => return None,
+            }
+        }
+    }
+    Some(parts)
+}
+
+/// Splits `1Y-2M` into the numbers and their designators, `[("1", 'Y'), ("-2", 'M')]`.
+fn designators(text: &str) -> Option<Vec<(&str, char)>> {
+    let mut result = Vec::new();
+    let mut start = 0;
+    for (index, c) in text.char_indices() {
+        if c.is_ascii_alphabetic() {
+            if index == start {
+                return None;
+            }
+            result.push((&text[start..index], c));
+            start = index + 1;
+        }
+    }
+    if start != text.len() {
+        return None;
+    }
+    Some(result)
+}
@@ -0,0 +1,134 @@
+use chrono::prelude::*;
+
+use super::disambiguation::{Disambiguation, LocalTimeError};
+use super::shift::{apply, shift};
+use super::{calculate_with_options, CalculateOptions, DateComponent};
+
+/// Unit of a DateComponent, from the largest to the smallest.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Unit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+/// How to round when the value lies between two multiples of the increment.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest increment, ties away from zero.
+    HalfExpand,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards zero.
+    Trunc,
+}
+
+/// Options for `DateComponent::round`.
+#[derive(Debug, Clone)]
+pub struct RoundOptions<T: TimeZone> {
+    /// Smallest unit kept in the result; smaller units become zero.
+    pub smallest_unit: Unit,
+    /// Round to a multiple of this many `smallest_unit`s. `0` is treated as `1`.
+    pub increment: u32,
+    /// Rounding mode.
+    pub mode: RoundingMode,
+    /// Date the DateComponent is applied to, usually the `from_datetime` it was calculated with.
+    /// Needed to know the length of months and days.
+    pub relative_to: DateTime<T>,
+    /// How to resolve local times that are ambiguous or do not exist.
+    pub disambiguation: Disambiguation,
+}
+
+impl DateComponent {
+    /// Returns a DateComponent rounded to a multiple of `smallest_unit`,
+    /// carrying into larger units (e.g. 59 minutes 36 seconds rounds to 1 hour).
+    pub fn round<T: TimeZone>(&self, options: RoundOptions<T>) -> Result<DateComponent, LocalTimeError> {
+        let disambiguation = options.disambiguation;
+        let increment = options.increment.max(1) as i64;
+
+        // An inverted DateComponent was measured forward from the earlier date,
+        // so it is rounded from there as well.
+        let applied = apply(&options.relative_to, self, disambiguation)?;
+        let (start, end) = if self.invert {
+            (applied, options.relative_to)
+        } else {
+            (options.relative_to, applied)
+        };
+
+        let year = self.year as i64;
+        let month = self.month as i64;
+        let day = self.day as i64;
+        let time = self.hour as i64 * 3600 + self.minute as i64 * 60 + self.second as i64;
+
+        let (lower, upper) = match options.smallest_unit {
+            Unit::Year => {
+                let whole = year / increment * increment;
+                (
+                    shift(&start, whole * 12, 0, 0, disambiguation)?,
+                    shift(&start, (whole + increment) * 12, 0, 0, disambiguation)?,
+                )
+            }
+            Unit::Month => {
+                let months = year * 12 + month;
+                let whole = months / increment * increment;
+                (
+                    shift(&start, whole, 0, 0, disambiguation)?,
+                    shift(&start, whole + increment, 0, 0, disambiguation)?,
+                )
+            }
+            Unit::Week => {
+                let whole = day / 7 / increment * increment * 7;
+                (
+                    shift(&start, year * 12 + month, whole, 0, disambiguation)?,
+                    shift(&start, year * 12 + month, whole + increment * 7, 0, disambiguation)?,
+                )
+            }
+            Unit::Day => {
+                let whole = day / increment * increment;
+                (
+                    shift(&start, year * 12 + month, whole, 0, disambiguation)?,
+                    shift(&start, year * 12 + month, whole + increment, 0, disambiguation)?,
+                )
+            }
+            Unit::Hour | Unit::Minute | Unit::Second => {
+                let unit = match options.smallest_unit {
+                    Unit::Hour => 3600,
+                    Unit::Minute => 60,
+                    _ => 1,
+                } * increment;
+                let whole = time / unit * unit;
+                (
+                    shift(&start, year * 12 + month, day, whole, disambiguation)?,
+                    shift(&start, year * 12 + month, day, whole + unit, disambiguation)?,
+                )
+            }
+        };
+
+        let progress = end.signed_duration_since(lower.clone()).num_seconds();
+        let length = upper.clone().signed_duration_since(lower.clone()).num_seconds();
+        let expand = progress > 0
+            && match options.mode {
+                RoundingMode::HalfExpand => progress * 2 >= length,
+                RoundingMode::Ceil => !self.invert,
+                RoundingMode::Floor => self.invert,
+                RoundingMode::Trunc => false,
+            };
+
+        let options = CalculateOptions {
+            disambiguation,
+            ..CalculateOptions::default()
+        };
+        let bound = if expand { upper } else { lower };
+        if self.invert {
+            calculate_with_options(&bound, &start, options)
+        } else {
+            calculate_with_options(&start, &bound, options)
+        }
+    }
+}
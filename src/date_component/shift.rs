@@ -0,0 +1,77 @@
+use chrono::prelude::*;
+use chrono::{Duration, Months};
+
+use super::disambiguation::{resolve, Disambiguation, LocalTimeError};
+use super::DateComponent;
+
+/// Move `datetime` by the given calendar months, then days (local wall-clock),
+/// then an exact number of seconds. Each value moves backward when negative.
+/// Month arithmetic clamps to the last day of the target month
+/// (e.g. 2023-01-31 + 1 month = 2023-02-28).
+pub(crate) fn shift<T: TimeZone>(
+    datetime: &DateTime<T>,
+    months: i64,
+    days: i64,
+    seconds: i64,
+    disambiguation: Disambiguation,
+) -> Result<DateTime<T>, LocalTimeError> {
+    let naive = datetime.naive_local();
+    let date = add_days(add_months(naive.date(), months), days);
+    let moved = resolve(&datetime.timezone(), &date.and_time(naive.time()), disambiguation)?;
+    Ok(moved + Duration::seconds(seconds))
+}
+
+fn add_months(date: NaiveDate, months: i64) -> NaiveDate {
+    if months < 0 {
+        date.checked_sub_months(Months::new(months.unsigned_abs() as u32))
+    } else {
+        date.checked_add_months(Months::new(months as u32))
+    }
+    .expect("date out of range")
+}
+
+fn add_days(date: NaiveDate, days: i64) -> NaiveDate {
+    date.checked_add_signed(Duration::days(days)).expect("date out of range")
+}
+
+/// Move `datetime` by the years, months, days and time of `component`.
+/// An inverted component moves backward to the date it was measured from:
+/// `datetime` minus `interval_seconds`, when the calendar fields reach `datetime` from there,
+/// and otherwise `datetime` minus the months, then the days, then the time.
+pub(crate) fn apply<T: TimeZone>(
+    datetime: &DateTime<T>,
+    component: &DateComponent,
+    disambiguation: Disambiguation,
+) -> Result<DateTime<T>, LocalTimeError> {
+    let months = component.year as i64 * 12 + component.month as i64;
+    let days = component.day as i64;
+    let seconds =
+        component.hour as i64 * 3600 + component.minute as i64 * 60 + component.second as i64;
+    if !component.invert {
+        return shift(datetime, months, days, seconds, disambiguation);
+    }
+    // Several dates can reach `datetime` (2023-01-28 to 2023-01-31 + 1 month are all 2023-02-28),
+    // the recorded length tells which one the component was calculated from.
+    let measured_from = datetime.clone() - Duration::seconds(component.interval_seconds as i64);
+    if shift(&measured_from, months, days, seconds, disambiguation).as_ref() == Ok(datetime) {
+        return Ok(measured_from);
+    }
+    shift(datetime, -months, -days, -seconds, disambiguation)
+}
+
+impl DateComponent {
+    /// Returns `datetime` moved by this DateComponent, so that
+    /// `calculate(a, b).apply_to(a)` is `b` (to the second).
+    /// Years, months and days move the local date, clamping to the end of shorter months,
+    /// and the local time is resolved as specified by `disambiguation` if the offset changes.
+    /// Hours, minutes and seconds are then added as elapsed time.
+    /// An inverted DateComponent moves backward to the date it was calculated from when its
+    /// `interval_seconds` lead there, and otherwise subtracts months, then days, then the time.
+    pub fn apply_to<T: TimeZone>(
+        &self,
+        datetime: &DateTime<T>,
+        disambiguation: Disambiguation,
+    ) -> Result<DateTime<T>, LocalTimeError> {
+        apply(datetime, self, disambiguation)
+    }
+}
@@ -0,0 +1,117 @@
+use alloc::string::{String, ToString};
+use chrono::prelude::*;
+use chrono_tz::Tz;
+use core::fmt;
+
+use super::disambiguation::{resolve, Disambiguation};
+use super::{calculate, DateComponent};
+
+/// Formats of local datetimes accepted by `parse_datetime`, besides RFC 3339 and `%Y-%m-%d`.
+const LOCAL_FORMATS: [&str; 4] = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%d %H:%M"];
+
+/// Error returned when a name is not a timezone of the IANA timezone database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownTimezoneError {
+    /// The name that was looked up.
+    pub name: String,
+}
+
+impl fmt::Display for UnknownTimezoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown timezone {:?}", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownTimezoneError {}
+
+/// Returns the timezone of the IANA timezone database named `name`, e.g. `Asia/Tokyo`.
+pub fn parse_timezone(name: &str) -> Result<Tz, UnknownTimezoneError> {
+    name.parse().map_err(|_| UnknownTimezoneError { name: name.to_string() })
+}
+
+/// Error returned by `calculate_in_zone` and `calculate_str`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ZoneCalculateError {
+    /// The timezone name is not in the IANA timezone database.
+    UnknownTimezone(UnknownTimezoneError),
+    /// The text is not an RFC 3339 or local ISO 8601 datetime.
+    InvalidDateTime(String),
+    /// The Unix timestamp cannot be represented.
+    TimestampOutOfRange(i64),
+}
+
+impl fmt::Display for ZoneCalculateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ZoneCalculateError::UnknownTimezone(error) => error.fmt(f),
+            ZoneCalculateError::InvalidDateTime(text) => write!(f, "invalid datetime {:?}", text),
+            ZoneCalculateError::TimestampOutOfRange(timestamp) => write!(f, "timestamp {} out of range", timestamp),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ZoneCalculateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ZoneCalculateError::UnknownTimezone(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<UnknownTimezoneError> for ZoneCalculateError {
+    fn from(error: UnknownTimezoneError) -> Self {
+        ZoneCalculateError::UnknownTimezone(error)
+    }
+}
+
+/// Returns a DateComponent object that represents the difference between two Unix timestamps
+/// in seconds, calculated in the timezone named `timezone` (e.g. `Europe/Paris`).
+pub fn calculate_in_zone(
+    from_timestamp: i64,
+    to_timestamp: i64,
+    timezone: &str,
+) -> Result<DateComponent, ZoneCalculateError> {
+    let timezone = parse_timezone(timezone)?;
+    let from = from_timestamp_in(from_timestamp, &timezone)?;
+    let to = from_timestamp_in(to_timestamp, &timezone)?;
+    Ok(calculate(&from, &to))
+}
+
+/// Returns a DateComponent object that represents the difference between two datetimes,
+/// calculated in the timezone named `timezone` (e.g. `Asia/Tokyo`).
+/// The datetimes are read by `parse_datetime`.
+pub fn calculate_str(from: &str, to: &str, timezone: &str) -> Result<DateComponent, ZoneCalculateError> {
+    let timezone = parse_timezone(timezone)?;
+    let from = parse_datetime(from, &timezone)?;
+    let to = parse_datetime(to, &timezone)?;
+    Ok(calculate(&from, &to))
+}
+
+fn from_timestamp_in(timestamp: i64, timezone: &Tz) -> Result<DateTime<Tz>, ZoneCalculateError> {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|datetime| datetime.with_timezone(timezone))
+        .ok_or(ZoneCalculateError::TimestampOutOfRange(timestamp))
+}
+
+/// Parses an RFC 3339 datetime (`2024-01-01T00:00:00+09:00`) converted to `timezone`,
+/// or a local datetime or date in `timezone` (`2024-01-01T00:00:00`, `2024-01-01 00:00`,
+/// `2024-01-01`). Local times are resolved with `Disambiguation::Compatible`: a time skipped
+/// by a DST gap is moved forward by the length of the gap, and a repeated time is the earlier one.
+pub fn parse_datetime(text: &str, timezone: &Tz) -> Result<DateTime<Tz>, ZoneCalculateError> {
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(text) {
+        return Ok(datetime.with_timezone(timezone));
+    }
+    let local = LOCAL_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .ok_or_else(|| ZoneCalculateError::InvalidDateTime(text.to_string()))?;
+    Ok(resolve(timezone, &local, Disambiguation::Compatible).expect("compatible disambiguation always resolves"))
+}
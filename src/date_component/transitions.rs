@@ -0,0 +1,115 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use chrono::prelude::*;
+use chrono::Duration;
+use chrono_tz::{OffsetName, Tz};
+
+use super::{calculate_with_mode, DateComponent, TimeMode};
+
+/// Offsets are compared at this interval to find transitions. Zones do not change
+/// their offset twice within it.
+const SCAN_STEP_SECONDS: i64 = 6 * 3600;
+
+/// A change of the UTC offset of a timezone, e.g. the start or end of DST.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffsetTransition {
+    /// First instant at which the new offset applies.
+    pub instant: DateTime<Utc>,
+    /// Offset before the transition.
+    pub old_offset: FixedOffset,
+    /// Offset from the transition on.
+    pub new_offset: FixedOffset,
+    /// Abbreviation of the timezone from the transition on (e.g. "PDT"), if any.
+    pub abbreviation: Option<String>,
+}
+
+impl OffsetTransition {
+    /// How much the offset changed, positive when clocks moved forward.
+    pub fn change(&self) -> Duration {
+        Duration::seconds((self.new_offset.local_minus_utc() - self.old_offset.local_minus_utc()) as i64)
+    }
+}
+
+/// A DateComponent along with the offset transitions crossed by the interval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionReport {
+    /// Difference between the from and to datetime, see `calculate`.
+    pub component: DateComponent,
+    /// Offset transitions crossed, in chronological order.
+    pub transitions: Vec<OffsetTransition>,
+    /// Is true if the wall-clock time and the elapsed time of the interval differ.
+    pub wall_clock_differs: bool,
+}
+
+/// Returns the offset transitions of the timezone of `from_datetime` that happen
+/// after the earlier and no later than the later of the two datetimes, in chronological order.
+pub fn offset_transitions(from_datetime: &DateTime<Tz>, to_datetime: &DateTime<Tz>) -> Vec<OffsetTransition> {
+    let timezone = from_datetime.timezone();
+    let (start, end) = if from_datetime <= to_datetime {
+        (from_datetime.timestamp(), to_datetime.timestamp())
+    } else {
+        (to_datetime.timestamp(), from_datetime.timestamp())
+    };
+
+    scan_transitions(&timezone, start, end)
+        .into_iter()
+        .map(|(timestamp, old_offset, new_offset)| {
+            let instant = DateTime::from_timestamp(timestamp, 0).expect("timestamp out of range");
+            OffsetTransition {
+                instant,
+                old_offset,
+                new_offset,
+                abbreviation: timezone
+                    .offset_from_utc_datetime(&instant.naive_utc())
+                    .abbreviation()
+                    .map(String::from),
+            }
+        })
+        .collect()
+}
+
+/// Returns the offset changes of `timezone` after `start` and no later than `end`,
+/// as the first second of the new offset, the old offset and the new offset.
+pub(crate) fn scan_transitions(timezone: &Tz, start: i64, end: i64) -> Vec<(i64, FixedOffset, FixedOffset)> {
+    let mut transitions = Vec::new();
+    let mut previous = start;
+    let mut previous_offset = offset_at(timezone, previous);
+    while previous < end {
+        let next = (previous + SCAN_STEP_SECONDS).min(end);
+        let next_offset = offset_at(timezone, next);
+        if next_offset != previous_offset {
+            // binary search for the first second with the new offset
+            let (mut low, mut high) = (previous, next);
+            while high - low > 1 {
+                let middle = low + (high - low) / 2;
+                if offset_at(timezone, middle) == previous_offset {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            transitions.push((high, previous_offset, next_offset));
+        }
+        previous = next;
+        previous_offset = next_offset;
+    }
+    transitions
+}
+
+/// Returns the difference between the from and to datetime along with the
+/// offset transitions crossed by the interval.
+pub fn calculate_with_transitions(from_datetime: &DateTime<Tz>, to_datetime: &DateTime<Tz>) -> TransitionReport {
+    let component = calculate_with_mode(from_datetime, to_datetime, TimeMode::Exact);
+    let wall_clock = calculate_with_mode(from_datetime, to_datetime, TimeMode::WallClock);
+    TransitionReport {
+        component,
+        transitions: offset_transitions(from_datetime, to_datetime),
+        wall_clock_differs: wall_clock.interval_seconds != component.interval_seconds
+            || wall_clock.invert != component.invert,
+    }
+}
+
+pub(crate) fn offset_at(timezone: &Tz, timestamp: i64) -> FixedOffset {
+    let datetime = DateTime::from_timestamp(timestamp, 0).expect("timestamp out of range");
+    timezone.offset_from_utc_datetime(&datetime.naive_utc()).fix()
+}
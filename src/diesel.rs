@@ -0,0 +1,74 @@
+//! Diesel `FromSql` and `ToSql` implementations for intervals.
+//!
+//! PostgreSQL `interval` columns are read and written natively. SQLite has no interval
+//! type, so intervals are stored in `Text` columns as ISO 8601 (e.g. `P1Y2M3DT4H5M6S`),
+//! and text in any PostgreSQL interval style is accepted when reading.
+
+use std::convert::TryFrom;
+
+use ::diesel::deserialize::{self, FromSql};
+use ::diesel::pg::data_types::PgInterval;
+use ::diesel::pg::{Pg, PgValue};
+use ::diesel::serialize::{self, IsNull, Output, ToSql};
+use ::diesel::sql_types::{BigInt, Integer, Interval, Text};
+use ::diesel::sqlite::{Sqlite, SqliteValue};
+
+use crate::date_component::{DateComponent, IntervalStyle, PostgresInterval};
+
+impl FromSql<Interval, Pg> for PostgresInterval {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let interval = <PgInterval as FromSql<Interval, Pg>>::from_sql(value)?;
+        Ok(PostgresInterval::new(interval.months, interval.days, interval.microseconds))
+    }
+}
+
+impl ToSql<Interval, Pg> for PostgresInterval {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<BigInt, Pg>::to_sql(&self.microseconds, out)?;
+        ToSql::<Integer, Pg>::to_sql(&self.days, out)?;
+        ToSql::<Integer, Pg>::to_sql(&self.months, out)?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Interval, Pg> for DateComponent {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let interval = <PostgresInterval as FromSql<Interval, Pg>>::from_sql(value)?;
+        Ok(DateComponent::try_from(interval)?)
+    }
+}
+
+impl ToSql<Interval, Pg> for DateComponent {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let interval = PostgresInterval::try_from(*self)?;
+        ToSql::<Interval, Pg>::to_sql(&interval, &mut out.reborrow())
+    }
+}
+
+impl FromSql<Text, Sqlite> for PostgresInterval {
+    fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        let text = <String as FromSql<Text, Sqlite>>::from_sql(value)?;
+        Ok(text.parse()?)
+    }
+}
+
+impl ToSql<Text, Sqlite> for PostgresInterval {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.format(IntervalStyle::Iso8601));
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Text, Sqlite> for DateComponent {
+    fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        let interval = <PostgresInterval as FromSql<Text, Sqlite>>::from_sql(value)?;
+        Ok(DateComponent::try_from(interval)?)
+    }
+}
+
+impl ToSql<Text, Sqlite> for DateComponent {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(PostgresInterval::try_from(*self)?.format(IntervalStyle::Iso8601));
+        Ok(IsNull::No)
+    }
+}
@@ -0,0 +1,122 @@
+//! C ABI for `calculate` over Unix timestamps and IANA timezone names.
+//!
+//! The header `include/date_component.h` is generated from this module by cbindgen.
+//! The library is an rlib by default, build the C library with
+//! `cargo rustc --release --lib --features ffi --crate-type cdylib`
+//! (or `--crate-type staticlib`).
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+
+use chrono::prelude::*;
+use chrono_tz::Tz;
+
+use crate::date_component::{calculate, DateComponent};
+
+/// `DateComponent` with a C layout.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct CDateComponent {
+    pub year: i64,
+    pub month: i64,
+    pub week: i64,
+    pub modulo_days: i64,
+    pub day: i64,
+    pub hour: i64,
+    pub minute: i64,
+    pub second: i64,
+    pub interval_seconds: i64,
+    pub interval_minutes: i64,
+    pub interval_hours: i64,
+    pub interval_days: i64,
+    pub invert: bool,
+}
+
+/// Result of the C functions.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CDateComponentStatus {
+    Ok = 0,
+    /// A pointer argument is null.
+    NullPointer = 1,
+    /// The timezone name is not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// The timezone name is not an IANA timezone.
+    UnknownTimezone = 3,
+    /// A timestamp is out of the range supported by chrono.
+    TimestampOutOfRange = 4,
+}
+
+impl From<DateComponent> for CDateComponent {
+    fn from(component: DateComponent) -> Self {
+        CDateComponent {
+            year: component.year as i64,
+            month: component.month as i64,
+            week: component.week as i64,
+            modulo_days: component.modulo_days as i64,
+            day: component.day as i64,
+            hour: component.hour as i64,
+            minute: component.minute as i64,
+            second: component.second as i64,
+            interval_seconds: component.interval_seconds as i64,
+            interval_minutes: component.interval_minutes as i64,
+            interval_hours: component.interval_hours as i64,
+            interval_days: component.interval_days as i64,
+            invert: component.invert,
+        }
+    }
+}
+
+/// Calculates the DateComponent between two Unix timestamps in seconds, in the IANA
+/// timezone `timezone` (e.g. "Asia/Tokyo"), and writes it to `out`.
+/// `out` is left unchanged unless `DATE_COMPONENT_STATUS_OK` is returned.
+///
+/// # Safety
+///
+/// `timezone` must be null or a NUL-terminated string, and `out` must be null or
+/// point to a writable `DateComponent`.
+#[no_mangle]
+pub unsafe extern "C" fn date_component_calculate(
+    from_timestamp: i64,
+    to_timestamp: i64,
+    timezone: *const c_char,
+    out: *mut CDateComponent,
+) -> CDateComponentStatus {
+    if timezone.is_null() || out.is_null() {
+        return CDateComponentStatus::NullPointer;
+    }
+    let timezone = match CStr::from_ptr(timezone).to_str() {
+        Ok(name) => name,
+        Err(_) => return CDateComponentStatus::InvalidUtf8,
+    };
+    let timezone: Tz = match timezone.parse() {
+        Ok(timezone) => timezone,
+        Err(_) => return CDateComponentStatus::UnknownTimezone,
+    };
+    let (from, to) = match (
+        DateTime::from_timestamp(from_timestamp, 0),
+        DateTime::from_timestamp(to_timestamp, 0),
+    ) {
+        (Some(from), Some(to)) => (from.with_timezone(&timezone), to.with_timezone(&timezone)),
+        _ => return CDateComponentStatus::TimestampOutOfRange,
+    };
+
+    *out = calculate(&from, &to).into();
+    CDateComponentStatus::Ok
+}
+
+/// Returns a static NUL-terminated description of `status`, a `DateComponentStatus`.
+/// Values that are not a `DateComponentStatus` get a generic description.
+#[no_mangle]
+pub extern "C" fn date_component_status_message(status: c_int) -> *const c_char {
+    // The status is taken as an int: C may pass any value, which is not a valid Rust enum.
+    let message: &'static [u8] = match status {
+        s if s == CDateComponentStatus::Ok as c_int => b"ok\0",
+        s if s == CDateComponentStatus::NullPointer as c_int => b"null pointer\0",
+        s if s == CDateComponentStatus::InvalidUtf8 as c_int => b"timezone is not valid UTF-8\0",
+        s if s == CDateComponentStatus::UnknownTimezone as c_int => b"unknown timezone\0",
+        s if s == CDateComponentStatus::TimestampOutOfRange as c_int => b"timestamp out of range\0",
+        _ => b"unknown status\0",
+    };
+    message.as_ptr() as *const c_char
+}
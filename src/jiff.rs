@@ -0,0 +1,60 @@
+//! `ZonedDateTime` implementation for `jiff::Zoned`.
+//!
+//! Local times that are ambiguous or do not exist are resolved by jiff, whose
+//! disambiguation strategies have the same meaning as `Disambiguation`.
+
+use ::jiff::civil;
+use ::jiff::tz::{self, AmbiguousOffset};
+use ::jiff::Zoned;
+use chrono::prelude::*;
+
+use crate::date_component::{Disambiguation, LocalTimeError, ZonedDateTime};
+
+impl ZonedDateTime for Zoned {
+    fn epoch_nanos(&self) -> i128 {
+        self.timestamp().as_nanosecond()
+    }
+
+    fn local_datetime(&self) -> NaiveDateTime {
+        let local = self.datetime();
+        NaiveDate::from_ymd_opt(local.year() as i32, local.month() as u32, local.day() as u32)
+            .and_then(|date| {
+                date.and_hms_nano_opt(
+                    local.hour() as u32,
+                    local.minute() as u32,
+                    local.second() as u32,
+                    local.subsec_nanosecond() as u32,
+                )
+            })
+            .expect("date out of range")
+    }
+
+    fn to_timezone_of(&self, other: &Self) -> Self {
+        self.with_time_zone(other.time_zone().clone())
+    }
+
+    fn resolve_local(&self, local: &NaiveDateTime, disambiguation: Disambiguation) -> Result<Self, LocalTimeError> {
+        let datetime = civil::DateTime::new(
+            local.year() as i16,
+            local.month() as i8,
+            local.day() as i8,
+            local.hour() as i8,
+            local.minute() as i8,
+            local.second() as i8,
+            local.nanosecond() as i32,
+        )
+        .expect("date out of range");
+        let ambiguous = self.time_zone().to_ambiguous_zoned(datetime);
+        let disambiguation = match disambiguation {
+            Disambiguation::Earlier => tz::Disambiguation::Earlier,
+            Disambiguation::Later => tz::Disambiguation::Later,
+            Disambiguation::Compatible => tz::Disambiguation::Compatible,
+            Disambiguation::Reject => match ambiguous.offset() {
+                AmbiguousOffset::Unambiguous { .. } => tz::Disambiguation::Reject,
+                AmbiguousOffset::Gap { .. } => return Err(LocalTimeError::Nonexistent(*local)),
+                AmbiguousOffset::Fold { .. } => return Err(LocalTimeError::Ambiguous(*local)),
+            },
+        };
+        Ok(ambiguous.disambiguate(disambiguation).expect("date out of range"))
+    }
+}
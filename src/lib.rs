@@ -1,7 +1,93 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod calendar;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+#[cfg(feature = "polars")]
+pub mod polars;
+
+#[cfg(feature = "postgres-types")]
+mod postgres_types;
+
+#[cfg(feature = "sqlx")]
+mod sqlx;
+
+#[cfg(feature = "diesel")]
+mod diesel;
+
+#[cfg(feature = "time")]
+mod time;
+
+#[cfg(feature = "jiff")]
+mod jiff;
+
+#[cfg(feature = "prost")]
+pub mod prost;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 pub mod date_component {
     use chrono::prelude::*;
 
+    mod across;
+    mod backend;
+    #[cfg(feature = "polars")]
+    mod balance;
+    mod batch;
+    mod compare;
+    mod disambiguation;
+    mod humanize;
+    mod iso_week;
+    #[cfg(feature = "rayon")]
+    mod parallel;
+    mod postgres;
+    mod round;
+    mod shift;
+    #[cfg(feature = "chrono-tz")]
+    mod timezone;
+    #[cfg(feature = "chrono-tz")]
+    mod transitions;
+    #[cfg(all(feature = "chrono-tz", feature = "std"))]
+    mod zone_cache;
+
+    pub use across::{calculate_across, ZonePolicy};
+    #[cfg(feature = "polars")]
+    pub(crate) use balance::calculate_balanced;
+    pub use backend::ZonedDateTime;
+    pub use batch::{calculate_columns, calculate_many, DateComponentColumns};
+    pub use compare::{compare, RelativeDateComponent};
+    pub use disambiguation::{Disambiguation, LocalTimeError};
+    pub use iso_week::{calculate_iso_weeks, IsoWeekComponent};
+    #[cfg(feature = "rayon")]
+    pub use parallel::{par_calculate, par_calculate_pairs};
+    pub use postgres::{IntervalStyle, PostgresInterval, PostgresIntervalError};
+    pub use round::{RoundOptions, RoundingMode, Unit};
+    #[cfg(feature = "chrono-tz")]
+    pub use timezone::{
+        calculate_in_zone, calculate_str, parse_datetime, parse_timezone, UnknownTimezoneError, ZoneCalculateError,
+    };
+    #[cfg(feature = "chrono-tz")]
+    pub use transitions::{calculate_with_transitions, offset_transitions, OffsetTransition, TransitionReport};
+    #[cfg(all(feature = "chrono-tz", feature = "std"))]
+    pub use zone_cache::{CachedOffset, CachedTz};
+
     #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "diesel", derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow))]
+    #[cfg_attr(
+        feature = "diesel",
+        diesel(sql_type = diesel::sql_types::Interval, sql_type = diesel::sql_types::Text)
+    )]
     pub struct DateComponent {
         /// Number of years.
         pub year: isize,
@@ -31,48 +117,117 @@ pub mod date_component {
         pub invert: bool,
     }
 
+    /// How the hours, minutes and seconds of a DateComponent are measured.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+    pub enum TimeMode {
+        /// Calendar days are counted on the local date, and the remaining time is the
+        /// real elapsed time. e.g. from 00:30 to 02:30 on a day when clocks go back
+        /// 1 hour, 3 hours have elapsed.
+        #[default]
+        Exact,
+        /// Everything is counted on the local wall-clock, ignoring changes of the offset.
+        /// e.g. from 00:30 to 02:30 on a day when clocks go back 1 hour, the clock
+        /// moved 2 hours. The `interval_*` fields are wall-clock totals as well.
+        WallClock,
+    }
+
+    /// Options for `calculate_with_options`.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+    pub struct CalculateOptions {
+        /// How the hours, minutes and seconds are measured.
+        pub mode: TimeMode,
+        /// How to resolve local times that are ambiguous or do not exist.
+        pub disambiguation: Disambiguation,
+    }
+
     /// Returns a DateComponent object that represents the difference between the from and to datetime.
-    pub fn calculate<T: chrono::TimeZone>(from_datetime: &DateTime<T>, to_datetime: &DateTime<T>) -> DateComponent {
-        let timezone = from_datetime.timezone();
-        let to_datetime_in_from_tz = to_datetime.with_timezone(&timezone);
+    /// The time components are the real elapsed time, see `TimeMode::Exact`.
+    /// The datetimes may be chrono, `time` or `jiff` datetimes, see `ZonedDateTime`.
+    pub fn calculate<D: ZonedDateTime>(from_datetime: &D, to_datetime: &D) -> DateComponent {
+        calculate_with_mode(from_datetime, to_datetime, TimeMode::Exact)
+    }
+
+    /// Returns a DateComponent object that represents the difference between the from and to datetime,
+    /// measuring time as specified by `mode`.
+    /// Local times are resolved with `Disambiguation::Compatible`.
+    pub fn calculate_with_mode<D: ZonedDateTime>(
+        from_datetime: &D,
+        to_datetime: &D,
+        mode: TimeMode,
+    ) -> DateComponent {
+        let options = CalculateOptions {
+            mode,
+            disambiguation: Disambiguation::Compatible,
+        };
+        calculate_with_options(from_datetime, to_datetime, options)
+            .expect("compatible disambiguation always resolves")
+    }
+
+    /// Returns a DateComponent object that represents the difference between the from and to datetime,
+    /// measuring time and resolving local times as specified by `options`.
+    pub fn calculate_with_options<D: ZonedDateTime>(
+        from_datetime: &D,
+        to_datetime: &D,
+        options: CalculateOptions,
+    ) -> Result<DateComponent, LocalTimeError> {
+        match options.mode {
+            TimeMode::Exact => calculate_exact(from_datetime, to_datetime, options.disambiguation),
+            TimeMode::WallClock => {
+                // Local wall-clock times compared in a timezone without offset changes.
+                let from_local = Utc.from_utc_datetime(&from_datetime.local_datetime());
+                let to_local = Utc.from_utc_datetime(&to_datetime.to_timezone_of(from_datetime).local_datetime());
+                calculate_exact(&from_local, &to_local, options.disambiguation)
+            }
+        }
+    }
+
+    fn calculate_exact<D: ZonedDateTime>(
+        from_datetime: &D,
+        to_datetime: &D,
+        disambiguation: Disambiguation,
+    ) -> Result<DateComponent, LocalTimeError> {
+        let to_datetime_in_from_tz = to_datetime.to_timezone_of(from_datetime);
 
-        let duration = from_datetime.clone().signed_duration_since(to_datetime.clone());
-        let seconds = duration.num_seconds();
+        let seconds = backend::seconds_between(to_datetime, from_datetime);
         let (start, end, invert) = match seconds {
             x if x <= 0 => (from_datetime.clone(), to_datetime_in_from_tz, false),
             _ => (to_datetime_in_from_tz, from_datetime.clone(), true),
         };
 
+        let start_date = start.local_datetime().date();
+        let (end_date, intermediate) = last_whole_day(&start, &end, disambiguation)?;
+
         // Use mutable variables for interval components
-        let mut year = end.year() as i64 - start.year() as i64;
-        let mut month = end.month() as i64 - start.month() as i64;
-        let mut day = end.day() as i64 - start.day() as i64;
-        
-        // For DST handling, we need to use duration for time components
-        // instead of calculating differences directly
-        let duration_hours = duration.num_hours().abs() % 24;
-        let duration_minutes = duration.num_minutes().abs() % 60;
-        let duration_seconds = duration.num_seconds().abs() % 60;
+        let mut year = end_date.year() as i64 - start_date.year() as i64;
+        let mut month = end_date.month() as i64 - start_date.month() as i64;
+        let mut day = end_date.day() as i64 - start_date.day() as i64;
+
+        // The remaining time is the real elapsed time, which is not a whole
+        // number of days when the offset changes (DST).
+        let time_seconds = backend::seconds_between(&intermediate, &end);
 
         // Now handle date borrowing (days -> months -> years)
-        let (previous_year, previous_month) = if end.month() == 1 {
-            (end.year() - 1, 12)
+        let (previous_year, previous_month) = if end_date.month() == 1 {
+            (end_date.year() - 1, 12)
         } else {
-            (end.year(), end.month() - 1)
+            (end_date.year(), end_date.month() - 1)
         };
 
         if day < 0 {
             month -= 1;
             // Add days in the month *before* the end date's month.
             // Use get_nearest_day_before to find the last day of that month.
+            // This only depends on the calendar, not on the timezone: a local time
+            // may not exist at midnight but the date always does.
             let last_day_of_prev_month = get_nearest_day_before(
                 previous_year,
                 previous_month,
                 31, // Try 31, it will be adjusted down correctly
-                0, 0, 0, // Time doesn't matter for finding the last day
-                &timezone
             );
-            day += last_day_of_prev_month.day() as i64;
+            // The start day is clamped to that month, like adding months does
+            // (e.g. 2023-01-31 + 1 month = 2023-02-28), so the days never go negative.
+            let start_day = start_date.day().min(last_day_of_prev_month.day());
+            day = end_date.day() as i64 + (last_day_of_prev_month.day() - start_day) as i64;
         }
 
         if month < 0 {
@@ -80,142 +235,94 @@ pub mod date_component {
             year -= 1;
         };
 
-        // Calculate week and modulo_days based on the final adjusted day value
-        let final_year = year;
-        let final_month = month;
-        let mut final_day = day;
-        let mut final_hour = duration_hours;
-        let mut final_minute = duration_minutes;
-        let mut final_second = duration_seconds;
-
-        // Consistency check: fix cases where small time differences are incorrectly
-        // calculated as larger units due to crossing date boundaries
-        let total_seconds = duration.num_seconds().abs();
-        
-        // If total time is less than 1 day but we calculated days, adjust
-        if total_seconds < 86400 && final_day > 0 {
-            // For small time differences that cross boundaries, 
-            // use the total duration directly instead of calculated day components
-            final_day = 0;
-            final_hour = total_seconds / 3600;
-            final_minute = (total_seconds % 3600) / 60;
-            final_second = total_seconds % 60;
-        }
-        
-        // Similar check for hours when total time is less than 1 hour
-        if total_seconds < 3600 && final_hour > 0 {
-            let hour_seconds = final_hour * 3600;
-            let remaining_seconds = hour_seconds + final_minute * 60 + final_second;
-            
-            final_hour = 0;
-            final_minute = remaining_seconds / 60;
-            final_second = remaining_seconds % 60;
-        }
-        
-        // Similar check for minutes when total time is less than 1 minute
-        if total_seconds < 60 && final_minute > 0 {
-            let minute_seconds = final_minute * 60;
-            final_second = minute_seconds + final_second;
-            final_minute = 0;
-        }
-
-        let final_week = final_day / 7;
-        let final_modulo_days = final_day % 7;
+        let final_week = day / 7;
+        let final_modulo_days = day % 7;
 
         // Return the final DateComponent
-        DateComponent {
-            year: final_year as isize,
-            month: final_month as isize,
+        Ok(DateComponent {
+            year: year as isize,
+            month: month as isize,
             week: final_week as isize,
             modulo_days: final_modulo_days as isize,
-            day: final_day as isize,
-            hour: final_hour as isize,
-            minute: final_minute as isize,
-            second: final_second as isize,
-            interval_seconds: duration.num_seconds().abs() as isize,
-            interval_minutes: duration.num_minutes().abs() as isize,
-            interval_hours: duration.num_hours().abs() as isize,
-            interval_days: duration.num_days().abs() as isize,
+            day: day as isize,
+            hour: (time_seconds / 3600) as isize,
+            minute: ((time_seconds % 3600) / 60) as isize,
+            second: (time_seconds % 60) as isize,
+            interval_seconds: seconds.abs() as isize,
+            interval_minutes: (seconds / 60).abs() as isize,
+            interval_hours: (seconds / 3600).abs() as isize,
+            interval_days: (seconds / 86400).abs() as isize,
             invert,
+        })
+    }
+
+    /// Returns the last local date on which the time of day of `start` is reached again
+    /// no later than `end`, and the instant at which it is reached on that date.
+    /// Whole days on the local calendar are counted up to this date.
+    pub(crate) fn last_whole_day<D: ZonedDateTime>(
+        start: &D,
+        end: &D,
+        disambiguation: Disambiguation,
+    ) -> Result<(NaiveDate, D), LocalTimeError> {
+        let start_date = start.local_datetime().date();
+        let mut end_date = end.local_datetime().date();
+        let mut intermediate = start_of_day(start, start_date, end_date, disambiguation)?;
+        if end_date > start_date && intermediate.epoch_nanos() > end.epoch_nanos() {
+            end_date = end_date.pred_opt().expect("date out of range");
+            intermediate = start_of_day(start, start_date, end_date, disambiguation)?;
+        }
+        Ok((end_date, intermediate))
+    }
+
+    /// Returns the instant at which the local time of day of `start` is reached on `date`.
+    fn start_of_day<D: ZonedDateTime>(
+        start: &D,
+        start_date: NaiveDate,
+        date: NaiveDate,
+        disambiguation: Disambiguation,
+    ) -> Result<D, LocalTimeError> {
+        if date == start_date {
+            return Ok(start.clone());
         }
+        start.resolve_local(&date.and_time(start.local_datetime().time()), disambiguation)
     }
 
     /// Given date specified by year / month / day where the `day` may be invalid,
     /// (e.g. 2021-02-30), return the nearest valid day before it
     /// (e.g. 2021-02-28).
-    fn get_nearest_day_before<T: TimeZone>(
-        year: i32,
-        month: u32,
-        day: u32,
-        hour: u32,
-        min: u32,
-        sec: u32,
-        timezone: &T
-    ) -> DateTime<T> {
-        let mut subtract = 0;
-        loop {
-            match timezone.with_ymd_and_hms(year, month, day - subtract, hour, min, sec) {
-                chrono::LocalResult::None => subtract += 1,
-                chrono::LocalResult::Single(d) => {
-                    return d;
-                }
-                chrono::LocalResult::Ambiguous(d, _) => {
-                    return d;
-                }
-            }
-        }
+    pub(crate) fn get_nearest_day_before(year: i32, month: u32, day: u32) -> NaiveDate {
+        let day = day.min(crate::calendar::days_in_month(year, month));
+        NaiveDate::from_ymd_opt(year, month, day).expect("date out of range")
     }
 }
 
 #[cfg(test)]
 mod internal_tests {
     use chrono::prelude::*;
-
-    fn get_nearest_day_before<T: TimeZone>(
-        year: i32,
-        month: u32,
-        day: u32,
-        hour: u32,
-        min: u32,
-        sec: u32,
-        timezone: &T
-    ) -> DateTime<T> {
-        let mut subtract = 0;
-        loop {
-            match timezone.with_ymd_and_hms(year, month, day - subtract, hour, min, sec) {
-                chrono::LocalResult::None => subtract += 1,
-                chrono::LocalResult::Single(d) => {
-                    return d;
-                }
-                chrono::LocalResult::Ambiguous(d, _) => {
-                    return d;
-                }
-            }
-        }
-    }
+    use crate::date_component::get_nearest_day_before;
 
     #[test]
     fn test_get_nearest_day_before_regular() {
-        let dt = get_nearest_day_before(2023, 2, 30, 0, 0, 0, &Utc);
+        let dt = get_nearest_day_before(2023, 2, 30);
         assert_eq!(dt.day(), 28);
     }
 
     #[test]
     fn test_get_nearest_day_before_leap() {
-        let dt = get_nearest_day_before(2024, 2, 30, 0, 0, 0, &Utc);
+        let dt = get_nearest_day_before(2024, 2, 30);
         assert_eq!(dt.day(), 29);
     }
 
     #[test]
     fn test_get_nearest_day_before_big_month() {
-        let dt = get_nearest_day_before(2023, 1, 32, 0, 0, 0, &Utc);
+        let dt = get_nearest_day_before(2023, 1, 32);
         assert_eq!(dt.day(), 31);
     }
 
     #[test]
     fn test_get_nearest_day_before_edge_case() {
         // Test with day = 1, should return valid date
-        let dt = get_nearest_day_before(2023, 2, 1, 0, 0, 0, &Utc);
+        let dt = get_nearest_day_before(2023, 2, 1);
         assert_eq!(dt.day(), 1);
     }
 
@@ -223,7 +330,7 @@ mod internal_tests {
     fn test_potential_infinite_loop_prevention() {
         // This tests if the function would handle extremely large day values gracefully
         // It should not cause infinite loop even with very large subtract values
-        let dt = get_nearest_day_before(2023, 2, 100, 0, 0, 0, &Utc);
+        let dt = get_nearest_day_before(2023, 2, 100);
         assert_eq!(dt.day(), 28); // February 2023 has 28 days
     }
 }
@@ -0,0 +1,135 @@
+//! Polars expressions computing DateComponents between two datetime columns.
+//!
+//! ```ignore
+//! df.lazy().with_columns([date_component("start", "end")])
+//! ```
+
+use ::polars::prelude::*;
+use chrono::prelude::*;
+use chrono_tz::Tz;
+
+use crate::date_component::{calculate_balanced, DateComponent, Disambiguation, Unit};
+
+/// Options for `date_component_with_options`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateComponentExprOptions {
+    /// Largest unit of the result, the larger units being converted into it.
+    /// e.g. 1 year 2 months becomes 14 months with `Unit::Month`, or the number of
+    /// calendar days between the datetimes with `Unit::Day`.
+    pub largest_unit: Unit,
+    /// IANA name of the timezone in which the calendar difference is calculated.
+    /// Defaults to the timezone of the start column, or UTC when it has none.
+    pub timezone: Option<String>,
+}
+
+impl Default for DateComponentExprOptions {
+    fn default() -> Self {
+        DateComponentExprOptions {
+            largest_unit: Unit::Year,
+            timezone: None,
+        }
+    }
+}
+
+/// Fields of the struct column produced by the date component expressions.
+pub fn date_component_fields() -> Vec<Field> {
+    let mut fields: Vec<Field> = ["year", "month", "week", "day", "hour", "minute", "second"]
+        .iter()
+        .map(|name| Field::new((*name).into(), DataType::Int64))
+        .collect();
+    fields.push(Field::new("invert".into(), DataType::Boolean));
+    fields
+}
+
+/// Returns an expression computing the DateComponent between the datetime columns
+/// `start` and `end` as a struct column.
+pub fn date_component(start: &str, end: &str) -> Expr {
+    date_component_with_options(col(start), col(end), DateComponentExprOptions::default())
+}
+
+/// Returns an expression computing the DateComponent between the datetime expressions
+/// `start` and `end` as a struct column, as specified by `options`.
+pub fn date_component_with_options(start: Expr, end: Expr, options: DateComponentExprOptions) -> Expr {
+    map_multiple(
+        move |columns: &mut [Column]| calculate_columns(&columns[0], &columns[1], &options),
+        [start, end],
+        |_: &Schema, fields: &[Field]| {
+            Ok(Field::new(fields[0].name().clone(), DataType::Struct(date_component_fields())))
+        },
+    )
+}
+
+fn calculate_columns(start: &Column, end: &Column, options: &DateComponentExprOptions) -> PolarsResult<Column> {
+    let start_datetimes = start.datetime()?;
+    let end_datetimes = end.datetime()?;
+    polars_ensure!(
+        start_datetimes.len() == end_datetimes.len(),
+        ShapeMismatch: "start and end must have the same length"
+    );
+
+    let timezone_name = match (&options.timezone, start_datetimes.time_zone()) {
+        (Some(name), _) => name.to_string(),
+        (None, Some(name)) => name.to_string(),
+        (None, None) => "UTC".to_string(),
+    };
+    let timezone: Tz = timezone_name
+        .parse()
+        .map_err(|_| polars_err!(ComputeError: "unknown timezone {}", timezone_name))?;
+
+    let components: Vec<Option<DateComponent>> = start_datetimes
+        .physical()
+        .iter()
+        .zip(end_datetimes.physical().iter())
+        .map(|pair| match pair {
+            (Some(from), Some(to)) => {
+                let from = to_datetime(from, start_datetimes.time_unit(), &timezone)?;
+                let to = to_datetime(to, end_datetimes.time_unit(), &timezone)?;
+                let component = calculate_balanced(&from, &to, options.largest_unit, Disambiguation::Compatible)
+                    .expect("compatible disambiguation always resolves");
+                Ok(Some(component))
+            }
+            _ => Ok(None),
+        })
+        .collect::<PolarsResult<_>>()?;
+
+    let values = |field: fn(&DateComponent) -> isize| -> Series {
+        components
+            .iter()
+            .map(|c| c.as_ref().map(|c| field(c) as i64))
+            .collect::<Int64Chunked>()
+            .into_series()
+    };
+    let mut fields = vec![
+        values(|c| c.year).with_name("year".into()),
+        values(|c| c.month).with_name("month".into()),
+        values(|c| c.week).with_name("week".into()),
+        values(|c| c.day).with_name("day".into()),
+        values(|c| c.hour).with_name("hour".into()),
+        values(|c| c.minute).with_name("minute".into()),
+        values(|c| c.second).with_name("second".into()),
+    ];
+    fields.push(
+        components
+            .iter()
+            .map(|c| c.as_ref().map(|c| c.invert))
+            .collect::<BooleanChunked>()
+            .into_series()
+            .with_name("invert".into()),
+    );
+
+    let validity: BooleanChunked = components.iter().map(|c| Some(c.is_some())).collect();
+    let result = StructChunked::from_series(start.name().clone(), components.len(), fields.iter())?
+        .with_outer_validity(Some(validity.rechunk().downcast_as_array().values().clone()));
+    Ok(result.into_series().into_column())
+}
+
+fn to_datetime(value: i64, time_unit: TimeUnit, timezone: &Tz) -> PolarsResult<DateTime<Tz>> {
+    let datetime = match time_unit {
+        TimeUnit::Nanoseconds => Some(DateTime::from_timestamp_nanos(value)),
+        TimeUnit::Microseconds => DateTime::from_timestamp_micros(value),
+        TimeUnit::Milliseconds => DateTime::from_timestamp_millis(value),
+    };
+    datetime
+        .map(|d| d.with_timezone(timezone))
+        .ok_or_else(|| polars_err!(ComputeError: "datetime out of range"))
+}
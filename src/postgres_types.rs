@@ -0,0 +1,54 @@
+//! `postgres-types` conversions of PostgreSQL `interval` values, for use with
+//! the `postgres` and `tokio-postgres` clients.
+
+use std::convert::TryFrom;
+use std::error::Error;
+
+use bytes::{Buf, BufMut, BytesMut};
+use ::postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::date_component::{DateComponent, PostgresInterval};
+
+impl<'a> FromSql<'a> for PostgresInterval {
+    fn from_sql(_: &Type, mut raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        if raw.len() != 16 {
+            return Err("invalid message length: interval size mismatch".into());
+        }
+        // The binary format is the microseconds, days and months in network byte order.
+        let microseconds = raw.get_i64();
+        let days = raw.get_i32();
+        let months = raw.get_i32();
+        Ok(PostgresInterval::new(months, days, microseconds))
+    }
+
+    accepts!(INTERVAL);
+}
+
+impl ToSql for PostgresInterval {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.put_i64(self.microseconds);
+        out.put_i32(self.days);
+        out.put_i32(self.months);
+        Ok(IsNull::No)
+    }
+
+    accepts!(INTERVAL);
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for DateComponent {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(DateComponent::try_from(PostgresInterval::from_sql(ty, raw)?)?)
+    }
+
+    accepts!(INTERVAL);
+}
+
+impl ToSql for DateComponent {
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        PostgresInterval::try_from(*self)?.to_sql(ty, out)
+    }
+
+    accepts!(INTERVAL);
+    to_sql_checked!();
+}
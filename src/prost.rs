@@ -0,0 +1,157 @@
+//! Conversions between DateComponents and the protobuf `google.protobuf.Duration`
+//! and `google.type.Interval` messages.
+//!
+//! A `Duration` is a fixed number of seconds, so only DateComponents without years
+//! and months can be converted to one. An `Interval` keeps its start and end
+//! timestamps, from which the full DateComponent is calculated in UTC.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use chrono::prelude::*;
+use prost_types::{Duration, Timestamp};
+
+use crate::date_component::{calculate, DateComponent};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// The `google.type.Interval` message: the time between `start_time` (inclusive)
+/// and `end_time` (exclusive).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Interval {
+    #[prost(message, optional, tag = "1")]
+    pub start_time: Option<Timestamp>,
+    #[prost(message, optional, tag = "2")]
+    pub end_time: Option<Timestamp>,
+}
+
+/// Error returned when a DateComponent cannot be converted to or from a protobuf message.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ProstError {
+    /// Years and months have no fixed length and cannot be represented as a `Duration`.
+    CalendarComponent { year: isize, month: isize },
+    /// The `Interval` has no start or end time.
+    MissingTimestamp,
+    /// The timestamp is out of the range supported by chrono.
+    OutOfRange,
+}
+
+impl fmt::Display for ProstError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProstError::CalendarComponent { year, month } => write!(
+                f,
+                "{} years and {} months cannot be represented as a fixed duration",
+                year, month
+            ),
+            ProstError::MissingTimestamp => write!(f, "interval has no start or end time"),
+            ProstError::OutOfRange => write!(f, "timestamp out of range"),
+        }
+    }
+}
+
+impl std::error::Error for ProstError {}
+
+impl TryFrom<DateComponent> for Duration {
+    type Error = ProstError;
+
+    /// Returns `interval_seconds` as a `Duration`, negative when the DateComponent is inverted.
+    /// Fails when the DateComponent has years or months.
+    fn try_from(component: DateComponent) -> Result<Self, Self::Error> {
+        if component.year != 0 || component.month != 0 {
+            return Err(ProstError::CalendarComponent {
+                year: component.year,
+                month: component.month,
+            });
+        }
+        let sign = if component.invert { -1 } else { 1 };
+        Ok(Duration {
+            seconds: sign * component.interval_seconds as i64,
+            nanos: 0,
+        })
+    }
+}
+
+impl From<Duration> for DateComponent {
+    /// Breaks the `Duration` down into days of 24 hours, hours, minutes and seconds.
+    /// Fractions of a second are dropped, so a Duration of less than a second is
+    /// not inverted even when it is negative.
+    fn from(duration: Duration) -> Self {
+        let mut duration = duration;
+        duration.normalize();
+        let seconds = duration.seconds.unsigned_abs() as i64;
+        let days = seconds / SECONDS_PER_DAY;
+        DateComponent {
+            year: 0,
+            month: 0,
+            week: (days / 7) as isize,
+            modulo_days: (days % 7) as isize,
+            day: days as isize,
+            hour: (seconds % SECONDS_PER_DAY / 3600) as isize,
+            minute: (seconds % 3600 / 60) as isize,
+            second: (seconds % 60) as isize,
+            interval_seconds: seconds as isize,
+            interval_minutes: (seconds / 60) as isize,
+            interval_hours: (seconds / 3600) as isize,
+            interval_days: days as isize,
+            // Normalized seconds and nanos have the same sign.
+            invert: duration.seconds < 0,
+        }
+    }
+}
+
+impl Interval {
+    /// Returns an `Interval` from `start` to `end`.
+    pub fn new<T: TimeZone>(start: &DateTime<T>, end: &DateTime<T>) -> Self {
+        Interval {
+            start_time: Some(to_timestamp(start)),
+            end_time: Some(to_timestamp(end)),
+        }
+    }
+
+    /// Returns the DateComponent from the start time to the end time, calculated in UTC.
+    pub fn date_component(&self) -> Result<DateComponent, ProstError> {
+        let (start, end) = self.bounds()?;
+        Ok(calculate(&start, &end))
+    }
+
+    /// Returns the exact `Duration` from the start time to the end time, including nanoseconds.
+    pub fn duration(&self) -> Result<Duration, ProstError> {
+        let (start, end) = self.bounds()?;
+        let elapsed = end.signed_duration_since(start);
+        let mut duration = Duration {
+            seconds: elapsed.num_seconds(),
+            nanos: elapsed.subsec_nanos(),
+        };
+        duration.normalize();
+        Ok(duration)
+    }
+
+    fn bounds(&self) -> Result<(DateTime<Utc>, DateTime<Utc>), ProstError> {
+        match (self.start_time, self.end_time) {
+            (Some(start), Some(end)) => Ok((from_timestamp(start)?, from_timestamp(end)?)),
+            _ => Err(ProstError::MissingTimestamp),
+        }
+    }
+}
+
+impl TryFrom<Interval> for DateComponent {
+    type Error = ProstError;
+
+    fn try_from(interval: Interval) -> Result<Self, Self::Error> {
+        interval.date_component()
+    }
+}
+
+fn to_timestamp<T: TimeZone>(datetime: &DateTime<T>) -> Timestamp {
+    Timestamp {
+        seconds: datetime.timestamp(),
+        nanos: datetime.timestamp_subsec_nanos() as i32,
+    }
+}
+
+fn from_timestamp(timestamp: Timestamp) -> Result<DateTime<Utc>, ProstError> {
+    let mut timestamp = timestamp;
+    timestamp.normalize();
+    DateTime::from_timestamp(timestamp.seconds, timestamp.nanos as u32).ok_or(ProstError::OutOfRange)
+}
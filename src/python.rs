@@ -0,0 +1,186 @@
+//! Python bindings, built as the `date_component` extension module with maturin
+//! (`maturin develop --features python`), which builds the cdylib itself since the crate
+//! is only an rlib.
+//!
+//! ```python
+//! from datetime import datetime
+//! from zoneinfo import ZoneInfo
+//! import date_component
+//!
+//! tokyo = ZoneInfo("Asia/Tokyo")
+//! component = date_component.calculate(datetime(2023, 1, 31, 10, tzinfo=tokyo), datetime(2024, 3, 1, tzinfo=tokyo))
+//! component.isoformat()  # 'P1Y29DT14H'
+//! ```
+
+use std::convert::TryFrom;
+
+use chrono::prelude::*;
+use chrono_tz::Tz;
+use pyo3::exceptions::{PyOverflowError, PyTypeError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDate, PyDateTime, PyTzInfoAccess};
+
+use crate::date_component::{calculate as calculate_component, DateComponent, IntervalStyle, PostgresInterval};
+
+/// Python class mirroring `DateComponent`.
+#[pyclass(name = "DateComponent", module = "date_component", frozen, eq, skip_from_py_object)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PyDateComponent {
+    #[pyo3(get)]
+    pub year: i64,
+    #[pyo3(get)]
+    pub month: i64,
+    #[pyo3(get)]
+    pub week: i64,
+    #[pyo3(get)]
+    pub modulo_days: i64,
+    #[pyo3(get)]
+    pub day: i64,
+    #[pyo3(get)]
+    pub hour: i64,
+    #[pyo3(get)]
+    pub minute: i64,
+    #[pyo3(get)]
+    pub second: i64,
+    #[pyo3(get)]
+    pub interval_seconds: i64,
+    #[pyo3(get)]
+    pub interval_minutes: i64,
+    #[pyo3(get)]
+    pub interval_hours: i64,
+    #[pyo3(get)]
+    pub interval_days: i64,
+    #[pyo3(get)]
+    pub invert: bool,
+}
+
+impl From<DateComponent> for PyDateComponent {
+    fn from(component: DateComponent) -> Self {
+        PyDateComponent {
+            year: component.year as i64,
+            month: component.month as i64,
+            week: component.week as i64,
+            modulo_days: component.modulo_days as i64,
+            day: component.day as i64,
+            hour: component.hour as i64,
+            minute: component.minute as i64,
+            second: component.second as i64,
+            interval_seconds: component.interval_seconds as i64,
+            interval_minutes: component.interval_minutes as i64,
+            interval_hours: component.interval_hours as i64,
+            interval_days: component.interval_days as i64,
+            invert: component.invert,
+        }
+    }
+}
+
+impl From<&PyDateComponent> for DateComponent {
+    fn from(component: &PyDateComponent) -> Self {
+        DateComponent {
+            year: component.year as isize,
+            month: component.month as isize,
+            week: component.week as isize,
+            modulo_days: component.modulo_days as isize,
+            day: component.day as isize,
+            hour: component.hour as isize,
+            minute: component.minute as isize,
+            second: component.second as isize,
+            interval_seconds: component.interval_seconds as isize,
+            interval_minutes: component.interval_minutes as isize,
+            interval_hours: component.interval_hours as isize,
+            interval_days: component.interval_days as isize,
+            invert: component.invert,
+        }
+    }
+}
+
+#[pymethods]
+impl PyDateComponent {
+    /// Returns the ISO 8601 duration, e.g. `P1Y2M3DT4H5M6S`, with negative parts when inverted.
+    fn isoformat(&self) -> PyResult<String> {
+        PostgresInterval::try_from(DateComponent::from(self))
+            .map(|interval| interval.format(IntervalStyle::Iso8601))
+            .map_err(|e| PyOverflowError::new_err(e.to_string()))
+    }
+
+    /// Returns the DateComponent in words, e.g. `1 year, 2 months and 3 days`.
+    fn humanize(&self) -> String {
+        DateComponent::from(self).humanize()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "DateComponent(year={}, month={}, day={}, hour={}, minute={}, second={}, invert={})",
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+            if self.invert { "True" } else { "False" }
+        )
+    }
+}
+
+/// A `datetime.datetime` or `datetime.date` argument.
+enum Moment {
+    /// Aware, with a `zoneinfo.ZoneInfo` timezone.
+    Zoned(DateTime<Tz>),
+    /// Aware, with any other tzinfo, used as a fixed offset.
+    Fixed(DateTime<FixedOffset>),
+    /// Naive datetime, or a date at midnight.
+    Naive(NaiveDateTime),
+}
+
+impl Moment {
+    fn extract(value: &Bound<'_, PyAny>) -> PyResult<Moment> {
+        if let Ok(datetime) = value.cast::<PyDateTime>() {
+            if datetime.get_tzinfo().is_none() {
+                return Ok(Moment::Naive(value.extract()?));
+            }
+            if let Ok(zoned) = value.extract::<DateTime<Tz>>() {
+                return Ok(Moment::Zoned(zoned));
+            }
+            return Ok(Moment::Fixed(value.extract()?));
+        }
+        if value.cast::<PyDate>().is_ok() {
+            let date: NaiveDate = value.extract()?;
+            return Ok(Moment::Naive(date.and_time(NaiveTime::MIN)));
+        }
+        Err(PyTypeError::new_err("expected a datetime.datetime or datetime.date"))
+    }
+}
+
+/// Returns the DateComponent from `from_datetime` to `to_datetime`.
+///
+/// Aware datetimes are compared in the timezone of `from_datetime`, which follows
+/// daylight saving time when it is a `zoneinfo.ZoneInfo` and is a fixed offset otherwise.
+/// Naive datetimes and dates are compared as wall-clock times.
+#[pyfunction]
+#[pyo3(name = "calculate")]
+fn calculate_py(from_datetime: &Bound<'_, PyAny>, to_datetime: &Bound<'_, PyAny>) -> PyResult<PyDateComponent> {
+    let component = match (Moment::extract(from_datetime)?, Moment::extract(to_datetime)?) {
+        (Moment::Naive(from), Moment::Naive(to)) => {
+            calculate_component(&Utc.from_utc_datetime(&from), &Utc.from_utc_datetime(&to))
+        }
+        (Moment::Zoned(from), Moment::Zoned(to)) => calculate_component(&from, &to),
+        (Moment::Zoned(from), Moment::Fixed(to)) => calculate_component(&from, &to.with_timezone(&from.timezone())),
+        (Moment::Fixed(from), Moment::Zoned(to)) => calculate_component(&from, &to.fixed_offset()),
+        (Moment::Fixed(from), Moment::Fixed(to)) => calculate_component(&from, &to),
+        _ => {
+            return Err(PyTypeError::new_err(
+                "can't calculate between offset-naive and offset-aware datetimes",
+            ))
+        }
+    };
+    Ok(component.into())
+}
+
+/// The `date_component` Python module.
+#[pymodule]
+#[pyo3(name = "date_component")]
+pub fn date_component_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyDateComponent>()?;
+    module.add_function(wrap_pyfunction!(calculate_py, module)?)?;
+    Ok(())
+}
@@ -0,0 +1,108 @@
+//! SQLx `Type`, `Encode` and `Decode` implementations for intervals.
+//!
+//! PostgreSQL `interval` columns are read and written natively. SQLite has no interval
+//! type, so intervals are stored as ISO 8601 text (e.g. `P1Y2M3DT4H5M6S`), and text in
+//! any PostgreSQL interval style is accepted when reading.
+
+use std::convert::TryFrom;
+
+use ::sqlx::encode::IsNull;
+use ::sqlx::error::BoxDynError;
+use ::sqlx::postgres::types::PgInterval;
+use ::sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use ::sqlx::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+use ::sqlx::{Decode, Encode, Type};
+
+use crate::date_component::{DateComponent, IntervalStyle, PostgresInterval};
+
+impl From<PgInterval> for PostgresInterval {
+    fn from(interval: PgInterval) -> Self {
+        PostgresInterval::new(interval.months, interval.days, interval.microseconds)
+    }
+}
+
+impl From<PostgresInterval> for PgInterval {
+    fn from(interval: PostgresInterval) -> Self {
+        PgInterval {
+            months: interval.months,
+            days: interval.days,
+            microseconds: interval.microseconds,
+        }
+    }
+}
+
+impl Type<Postgres> for PostgresInterval {
+    fn type_info() -> PgTypeInfo {
+        <PgInterval as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for PostgresInterval {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        PgInterval::from(*self).encode_by_ref(buf)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for PostgresInterval {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => Ok(<PgInterval as Decode<Postgres>>::decode(value)?.into()),
+            // Unprepared queries return the interval as text in the session's IntervalStyle.
+            PgValueFormat::Text => Ok(value.as_str()?.parse()?),
+        }
+    }
+}
+
+impl Type<Postgres> for DateComponent {
+    fn type_info() -> PgTypeInfo {
+        <PgInterval as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for DateComponent {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        <PostgresInterval as Encode<Postgres>>::encode(PostgresInterval::try_from(*self)?, buf)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for DateComponent {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(DateComponent::try_from(<PostgresInterval as Decode<Postgres>>::decode(value)?)?)
+    }
+}
+
+impl Type<Sqlite> for PostgresInterval {
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for PostgresInterval {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'q>>) -> Result<IsNull, BoxDynError> {
+        <String as Encode<Sqlite>>::encode(self.format(IntervalStyle::Iso8601), buf)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for PostgresInterval {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(<&str as Decode<Sqlite>>::decode(value)?.parse()?)
+    }
+}
+
+impl Type<Sqlite> for DateComponent {
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for DateComponent {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'q>>) -> Result<IsNull, BoxDynError> {
+        <PostgresInterval as Encode<Sqlite>>::encode(PostgresInterval::try_from(*self)?, buf)
+    }
+}
+
+impl<'r> Decode<'r, Sqlite> for DateComponent {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(DateComponent::try_from(<PostgresInterval as Decode<Sqlite>>::decode(value)?)?)
+    }
+}
@@ -0,0 +1,37 @@
+//! `ZonedDateTime` implementation for `time::OffsetDateTime`.
+//!
+//! An `OffsetDateTime` has a fixed UTC offset rather than a timezone, so calendar dates
+//! are read at that offset and every local time exists exactly once.
+
+use std::convert::TryFrom;
+
+use ::time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
+use chrono::prelude::*;
+
+use crate::date_component::{Disambiguation, LocalTimeError, ZonedDateTime};
+
+impl ZonedDateTime for OffsetDateTime {
+    fn epoch_nanos(&self) -> i128 {
+        self.unix_timestamp_nanos()
+    }
+
+    fn local_datetime(&self) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(self.year(), u8::from(self.month()) as u32, self.day() as u32)
+            .and_then(|date| {
+                date.and_hms_nano_opt(self.hour() as u32, self.minute() as u32, self.second() as u32, self.nanosecond())
+            })
+            .expect("date out of range")
+    }
+
+    fn to_timezone_of(&self, other: &Self) -> Self {
+        self.to_offset(other.offset())
+    }
+
+    fn resolve_local(&self, local: &NaiveDateTime, _: Disambiguation) -> Result<Self, LocalTimeError> {
+        let month = Month::try_from(local.month() as u8).expect("month out of range");
+        let date = Date::from_calendar_date(local.year(), month, local.day() as u8).expect("date out of range");
+        let time = Time::from_hms_nano(local.hour() as u8, local.minute() as u8, local.second() as u8, local.nanosecond())
+            .expect("time out of range");
+        Ok(PrimitiveDateTime::new(date, time).assume_offset(self.offset()))
+    }
+}
@@ -0,0 +1,92 @@
+//! WebAssembly bindings. The library is an rlib by default, so build the wasm module as a
+//! cdylib and generate the JavaScript glue with wasm-bindgen:
+//!
+//! ```text
+//! cargo rustc --release --lib --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target bundler --out-dir pkg target/wasm32-unknown-unknown/release/date_component.wasm
+//! ```
+//!
+//! ```js
+//! import { calculate } from "date_component";
+//!
+//! const component = calculate(Date.parse("2023-01-31T01:00:00Z"), Date.parse("2024-02-29T15:00:00Z"), "Asia/Tokyo");
+//! component.year;  // 1
+//! component.day;   // 29
+//! component.hour;  // 14
+//! ```
+
+use chrono::prelude::*;
+use chrono_tz::Tz;
+use js_sys::{Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+use crate::date_component::{calculate as calculate_component, parse_timezone, DateComponent};
+
+#[wasm_bindgen(typescript_custom_section)]
+const DATE_COMPONENT_TYPE: &'static str = r#"
+export interface DateComponent {
+    year: number;
+    month: number;
+    week: number;
+    moduloDays: number;
+    day: number;
+    hour: number;
+    minute: number;
+    second: number;
+    intervalSeconds: number;
+    intervalMinutes: number;
+    intervalHours: number;
+    intervalDays: number;
+    invert: boolean;
+}
+"#;
+
+/// Returns the DateComponent between two epoch milliseconds (as returned by `Date.now()`
+/// or `Date.prototype.getTime()`), calculated in the IANA timezone `zone`.
+/// Sub-second precision is ignored.
+///
+/// Throws an `Error` when the timezone is unknown or a timestamp is out of range.
+#[wasm_bindgen(js_name = calculate, unchecked_return_type = "DateComponent")]
+pub fn calculate(from_ms: f64, to_ms: f64, zone: &str) -> Result<JsValue, JsError> {
+    let timezone = parse_timezone(zone).map_err(|e| JsError::new(&e.to_string()))?;
+    let from = to_datetime(from_ms, &timezone)?;
+    let to = to_datetime(to_ms, &timezone)?;
+    to_object(&calculate_component(&from, &to))
+}
+
+fn to_datetime(ms: f64, timezone: &Tz) -> Result<DateTime<Tz>, JsError> {
+    Some(ms)
+        .filter(|ms| ms.is_finite())
+        .and_then(|ms| DateTime::from_timestamp_millis(ms.floor() as i64))
+        .map(|datetime| datetime.with_timezone(timezone))
+        .ok_or_else(|| JsError::new(&format!("timestamp {} out of range", ms)))
+}
+
+fn to_object(component: &DateComponent) -> Result<JsValue, JsError> {
+    let object = Object::new();
+    let fields = [
+        ("year", component.year),
+        ("month", component.month),
+        ("week", component.week),
+        ("moduloDays", component.modulo_days),
+        ("day", component.day),
+        ("hour", component.hour),
+        ("minute", component.minute),
+        ("second", component.second),
+        ("intervalSeconds", component.interval_seconds),
+        ("intervalMinutes", component.interval_minutes),
+        ("intervalHours", component.interval_hours),
+        ("intervalDays", component.interval_days),
+    ];
+    for (name, value) in fields.iter() {
+        set(&object, name, &JsValue::from_f64(*value as f64))?;
+    }
+    set(&object, "invert", &JsValue::from_bool(component.invert))?;
+    Ok(object.into())
+}
+
+fn set(object: &Object, name: &str, value: &JsValue) -> Result<(), JsError> {
+    Reflect::set(object, &JsValue::from_str(name), value)
+        .map(|_| ())
+        .map_err(|_| JsError::new(&format!("cannot set {}", name)))
+}
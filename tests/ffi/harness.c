@@ -0,0 +1,52 @@
+/* C test harness of the date_component C ABI, compiled and run by tests/test_ffi.rs. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "date_component.h"
+
+static int failures = 0;
+
+#define CHECK(condition)                                                              \
+    do {                                                                              \
+        if (!(condition)) {                                                           \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #condition); \
+            failures++;                                                               \
+        }                                                                             \
+    } while (0)
+
+int main(void) {
+    DateComponent component;
+
+    /* 2023-01-31T10:00:00+09:00 to 2024-03-01T00:00:00+09:00 */
+    CHECK(date_component_calculate(1675126800, 1709218800, "Asia/Tokyo", &component) == DATE_COMPONENT_STATUS_OK);
+    CHECK(component.year == 1);
+    CHECK(component.month == 0);
+    CHECK(component.day == 29);
+    CHECK(component.week == 4);
+    CHECK(component.hour == 14);
+    CHECK(component.interval_seconds == 34092000);
+    CHECK(!component.invert);
+
+    CHECK(date_component_calculate(1709218800, 1675126800, "Asia/Tokyo", &component) == DATE_COMPONENT_STATUS_OK);
+    CHECK(component.day == 29);
+    CHECK(component.invert);
+
+    /* In UTC the same instants are 2023-01-31T01:00 to 2024-02-29T15:00. */
+    CHECK(date_component_calculate(1675126800, 1709218800, "UTC", &component) == DATE_COMPONENT_STATUS_OK);
+    CHECK(component.day == 29);
+    CHECK(component.hour == 14);
+
+    CHECK(date_component_calculate(0, 0, "Nowhere/Land", &component) == DATE_COMPONENT_STATUS_UNKNOWN_TIMEZONE);
+    CHECK(date_component_calculate(0, 0, NULL, &component) == DATE_COMPONENT_STATUS_NULL_POINTER);
+    CHECK(date_component_calculate(0, 0, "UTC", NULL) == DATE_COMPONENT_STATUS_NULL_POINTER);
+    CHECK(date_component_calculate(INT64_MAX, 0, "UTC", &component) == DATE_COMPONENT_STATUS_TIMESTAMP_OUT_OF_RANGE);
+    CHECK(strcmp(date_component_status_message(DATE_COMPONENT_STATUS_UNKNOWN_TIMEZONE), "unknown timezone") == 0);
+    CHECK(strcmp(date_component_status_message(42), "unknown status") == 0);
+
+    if (failures != 0) {
+        return 1;
+    }
+    printf("ok\n");
+    return 0;
+}
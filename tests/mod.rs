@@ -2,3 +2,30 @@ mod test_basic_units;
 mod test_dst;
 mod test_edge_cases;
 mod test_integrations;
+mod test_round;
+mod test_compare;
+mod test_across;
+mod test_time_mode;
+mod test_transitions;
+mod test_disambiguation;
+mod test_calendar;
+mod test_batch;
+mod test_arrow;
+mod test_polars;
+mod test_parallel;
+mod test_postgres;
+mod test_postgres_types;
+mod test_sqlx;
+mod test_diesel;
+mod test_prost;
+mod test_cli;
+mod test_ffi;
+mod test_python;
+mod test_humanize;
+mod test_wasm;
+mod test_time;
+mod test_jiff;
+mod test_no_std;
+mod test_timezone;
+mod test_zone_cache;
+mod test_iso_week;
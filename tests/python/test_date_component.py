@@ -0,0 +1,81 @@
+"""Tests of the date_component Python module.
+
+Run by tests/test_python.rs, or with pytest after `maturin develop --features python`.
+"""
+
+from datetime import date, datetime, timedelta, timezone
+from zoneinfo import ZoneInfo
+
+import date_component
+
+TOKYO = ZoneInfo("Asia/Tokyo")
+LONDON = ZoneInfo("Europe/London")
+
+
+def test_aware_datetimes():
+    component = date_component.calculate(datetime(2023, 1, 31, 10, tzinfo=TOKYO), datetime(2024, 3, 1, tzinfo=TOKYO))
+    assert (component.year, component.month, component.day, component.hour) == (1, 0, 29, 14)
+    assert component.week == 4
+    assert component.interval_seconds == 34092000
+    assert not component.invert
+    assert component.isoformat() == "P1Y29DT14H"
+    assert component.humanize() == "1 year, 29 days and 14 hours"
+
+
+def test_inverted():
+    component = date_component.calculate(datetime(2024, 3, 1, tzinfo=TOKYO), datetime(2023, 1, 31, 10, tzinfo=TOKYO))
+    assert component.invert
+    assert component.isoformat() == "P-1Y-29DT-14H"
+    assert component.humanize() == "1 year, 29 days and 14 hours ago"
+
+
+def test_zoneinfo_follows_dst():
+    # One calendar day, but 23 hours elapsed when clocks go forward.
+    component = date_component.calculate(datetime(2024, 3, 30, 12, tzinfo=LONDON), datetime(2024, 3, 31, 12, tzinfo=LONDON))
+    assert (component.day, component.hour) == (1, 0)
+    assert component.interval_hours == 23
+
+
+def test_fixed_offset_datetimes():
+    start = datetime(2024, 1, 1, 23, tzinfo=timezone(timedelta(hours=-5)))
+    end = datetime(2024, 1, 2, 4, tzinfo=timezone.utc)
+    component = date_component.calculate(start, end)
+    assert (component.day, component.hour) == (0, 0)
+
+    component = date_component.calculate(datetime(2024, 1, 1, tzinfo=timezone.utc), datetime(2024, 1, 1, 9, tzinfo=TOKYO))
+    assert (component.day, component.hour) == (0, 0)
+
+
+def test_naive_datetimes_and_dates():
+    component = date_component.calculate(datetime(2024, 1, 15, 6), datetime(2024, 3, 16, 12, 30))
+    assert (component.month, component.day, component.hour, component.minute) == (2, 1, 6, 30)
+
+    component = date_component.calculate(date(2020, 2, 29), date(2021, 2, 28))
+    assert (component.year, component.month, component.day) == (0, 11, 30)
+
+    component = date_component.calculate(date(2024, 1, 1), datetime(2024, 1, 1, 1))
+    assert component.hour == 1
+
+
+def test_equality_and_repr():
+    a = date_component.calculate(date(2024, 1, 1), date(2024, 2, 1))
+    b = date_component.calculate(datetime(2024, 1, 1), datetime(2024, 2, 1))
+    assert a == b
+    assert repr(a) == "DateComponent(year=0, month=1, day=0, hour=0, minute=0, second=0, invert=False)"
+    assert date_component.calculate(date(2024, 1, 1), date(2024, 1, 1)).humanize() == "0 seconds"
+
+
+def test_mixing_naive_and_aware_raises():
+    try:
+        date_component.calculate(datetime(2024, 1, 1), datetime(2024, 1, 1, tzinfo=TOKYO))
+    except TypeError:
+        pass
+    else:
+        raise AssertionError("expected TypeError")
+
+    try:
+        date_component.calculate("2024-01-01", date(2024, 1, 1))
+    except TypeError:
+        pass
+    else:
+        raise AssertionError("expected TypeError")
@@ -0,0 +1,64 @@
+use date_component::date_component::*;
+use chrono::prelude::*;
+use chrono_tz::Asia::Tokyo;
+#[cfg(feature = "chrono-tz")]
+use chrono_tz::America::New_York;
+
+#[test]
+fn test_calculate_across_zone_policies() {
+    // 2023-02-28 23:00 UTC
+    let from = Tokyo.with_ymd_and_hms(2023, 3, 1, 8, 0, 0).unwrap();
+    // 2023-04-01 08:30 in Tokyo
+    let to = Utc.with_ymd_and_hms(2023, 3, 31, 23, 30, 0).unwrap();
+
+    let sut = calculate_across(&from, &to, ZonePolicy::FromZone);
+    assert_eq!((sut.month, sut.day, sut.hour, sut.minute), (1, 0, 0, 30));
+
+    let sut = calculate_across(&from, &to, ZonePolicy::ToZone);
+    assert_eq!((sut.month, sut.day, sut.hour, sut.minute), (1, 3, 0, 30));
+
+    let sut = calculate_across(&from, &to, ZonePolicy::Utc);
+    assert_eq!((sut.month, sut.day, sut.hour, sut.minute), (1, 3, 0, 30));
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn test_calculate_across_explicit_zone() {
+    let from = Tokyo.with_ymd_and_hms(2023, 3, 1, 8, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 3, 31, 23, 30, 0).unwrap();
+
+    let sut = calculate_across(&from, &to, ZonePolicy::Explicit(New_York));
+    assert_eq!((sut.month, sut.day, sut.minute), (1, 3, 30));
+
+    assert_eq!(ZonePolicy::named("America/New_York"), Ok(ZonePolicy::Explicit(New_York)));
+    assert!(ZonePolicy::named("America/Gotham").is_err());
+}
+
+#[test]
+fn test_calculate_across_does_not_depend_on_argument_order() {
+    let tokyo = Tokyo.with_ymd_and_hms(2023, 3, 1, 8, 0, 0).unwrap();
+    let utc = Utc.with_ymd_and_hms(2023, 3, 31, 23, 30, 0).unwrap();
+
+    let forward = calculate_across(&tokyo, &utc, ZonePolicy::Utc);
+    let backward = calculate_across(&utc, &tokyo, ZonePolicy::Utc);
+    assert_eq!(forward.month, backward.month);
+    assert_eq!(forward.day, backward.day);
+    assert_eq!(forward.interval_seconds, backward.interval_seconds);
+    assert!(!forward.invert);
+    assert!(backward.invert);
+
+    // FromZone follows the first argument, so the calendar result changes with the order
+    let forward = calculate_across(&tokyo, &utc, ZonePolicy::FromZone);
+    let backward = calculate_across(&utc, &tokyo, ZonePolicy::FromZone);
+    assert_eq!(forward.day, 0);
+    assert_eq!(backward.day, 3);
+}
+
+#[test]
+fn test_calculate_across_same_timezone_matches_calculate() {
+    let from = Utc.with_ymd_and_hms(2020, 1, 6, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2021, 2, 14, 1, 1, 1).unwrap();
+
+    assert_eq!(calculate_across(&from, &to, ZonePolicy::FromZone), calculate(&from, &to));
+    assert_eq!(calculate_across(&from, &to, ZonePolicy::Utc), calculate(&from, &to));
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "arrow")]
+
+use date_component::arrow::*;
+use date_component::date_component::*;
+use arrow_array::types::IntervalMonthDayNano;
+use arrow_array::{Array, BooleanArray, Int32Array, TimestampMillisecondArray, TimestampSecondArray};
+use chrono::prelude::*;
+use chrono_tz::Asia::Tokyo;
+
+fn column(array: &arrow_array::StructArray, name: &str) -> Int32Array {
+    array
+        .column_by_name(name)
+        .unwrap()
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap()
+        .clone()
+}
+
+#[test]
+fn test_date_component_kernel_matches_calculate() {
+    let pairs = [
+        (Tokyo.with_ymd_and_hms(2023, 1, 31, 10, 0, 0).unwrap(), Tokyo.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap()),
+        (Tokyo.with_ymd_and_hms(2023, 5, 1, 0, 0, 0).unwrap(), Tokyo.with_ymd_and_hms(2023, 4, 2, 23, 59, 59).unwrap()),
+    ];
+    let from = TimestampSecondArray::from(pairs.iter().map(|(f, _)| f.timestamp()).collect::<Vec<_>>())
+        .with_timezone("+09:00");
+    let to = TimestampSecondArray::from(pairs.iter().map(|(_, t)| t.timestamp()).collect::<Vec<_>>())
+        .with_timezone("+09:00");
+
+    let sut = date_component_kernel(&from, &to).unwrap();
+    assert_eq!(sut.len(), 2);
+    assert_eq!(sut.fields(), &date_component_fields());
+    let invert = sut.column_by_name("invert").unwrap().as_any().downcast_ref::<BooleanArray>().unwrap();
+    for (i, (f, t)) in pairs.iter().enumerate() {
+        let expected = calculate(f, t);
+        assert_eq!(column(&sut, "year").value(i) as isize, expected.year);
+        assert_eq!(column(&sut, "month").value(i) as isize, expected.month);
+        assert_eq!(column(&sut, "day").value(i) as isize, expected.day);
+        assert_eq!(column(&sut, "hour").value(i) as isize, expected.hour);
+        assert_eq!(column(&sut, "minute").value(i) as isize, expected.minute);
+        assert_eq!(column(&sut, "second").value(i) as isize, expected.second);
+        assert_eq!(invert.value(i), expected.invert);
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn test_date_component_kernel_uses_timezone_metadata() {
+    // 2023-01-31 15:00 UTC is 2023-02-01 00:00 in Tokyo
+    let from = Utc.with_ymd_and_hms(2023, 1, 31, 15, 0, 0).unwrap().timestamp_millis();
+    let to = Utc.with_ymd_and_hms(2023, 2, 28, 15, 0, 0).unwrap().timestamp_millis();
+
+    let utc = date_component_kernel(
+        &TimestampMillisecondArray::from(vec![from]),
+        &TimestampMillisecondArray::from(vec![to]),
+    )
+    .unwrap();
+    assert_eq!((column(&utc, "month").value(0), column(&utc, "day").value(0)), (0, 28));
+
+    let tokyo = date_component_kernel(
+        &TimestampMillisecondArray::from(vec![from]).with_timezone("Asia/Tokyo"),
+        &TimestampMillisecondArray::from(vec![to]).with_timezone("+09:00"),
+    )
+    .unwrap();
+    assert_eq!((column(&tokyo, "month").value(0), column(&tokyo, "day").value(0)), (1, 0));
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+#[test]
+fn test_timezone_names_need_chrono_tz() {
+    let from = TimestampMillisecondArray::from(vec![0]).with_timezone("Asia/Tokyo");
+    let to = TimestampMillisecondArray::from(vec![0]).with_timezone("+09:00");
+    assert!(date_component_kernel(&from, &to).is_err());
+}
+
+#[test]
+fn test_kernels_propagate_nulls() {
+    let from = TimestampSecondArray::from(vec![Some(0), None, Some(86_400)]);
+    let to = TimestampSecondArray::from(vec![Some(90_061), Some(0), None]);
+
+    let sut = date_component_kernel(&from, &to).unwrap();
+    assert!(sut.is_valid(0));
+    assert!(sut.is_null(1));
+    assert!(sut.is_null(2));
+    assert_eq!(column(&sut, "day").value(0), 1);
+
+    let sut = month_day_nano_kernel(&from, &to).unwrap();
+    assert_eq!(sut.value(0), IntervalMonthDayNano::new(0, 1, 3_661_000_000_000));
+    assert!(sut.is_null(1));
+    assert!(sut.is_null(2));
+}
+
+#[test]
+fn test_month_day_nano_kernel_inverted() {
+    let from = Utc.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap().timestamp();
+    let to = Utc.with_ymd_and_hms(2023, 1, 10, 11, 0, 0).unwrap().timestamp();
+
+    let sut = month_day_nano_kernel(&TimestampSecondArray::from(vec![from]), &TimestampSecondArray::from(vec![to])).unwrap();
+    assert_eq!(sut.value(0), IntervalMonthDayNano::new(-14, -5, -3_600_000_000_000));
+}
+
+#[test]
+fn test_month_day_nano_kernel_sub_second() {
+    let from = TimestampMillisecondArray::from(vec![0, 1_500, 700, 0]);
+    let to = TimestampMillisecondArray::from(vec![1_500, 0, 1_200, 86_400_250]);
+
+    let sut = month_day_nano_kernel(&from, &to).unwrap();
+    assert_eq!(sut.value(0), IntervalMonthDayNano::new(0, 0, 1_500_000_000));
+    assert_eq!(sut.value(1), IntervalMonthDayNano::new(0, 0, -1_500_000_000));
+    assert_eq!(sut.value(2), IntervalMonthDayNano::new(0, 0, 500_000_000));
+    assert_eq!(sut.value(3), IntervalMonthDayNano::new(0, 1, 250_000_000));
+}
+
+#[test]
+fn test_kernels_errors() {
+    let from = TimestampSecondArray::from(vec![0, 1]);
+    let to = TimestampSecondArray::from(vec![0]);
+    assert!(date_component_kernel(&from, &to).is_err());
+
+    let from = TimestampSecondArray::from(vec![0]).with_timezone("Mars/Olympus_Mons");
+    assert!(month_day_nano_kernel(&from, &to).is_err());
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::too_many_arguments)]
+
 use date_component::date_component::*;
 use chrono::prelude::*;
 use test_case::test_case;
@@ -19,7 +21,7 @@ fn test_next_year(year_start: i32, year_end: i32) {
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.year, 1);
-    assert_eq!(sut.invert, false);
+    assert!(!sut.invert);
 
     // with shared timezone
     let from = Pacific.with_ymd_and_hms(year_start, 1, 1, 0, 0, 0).unwrap();
@@ -27,7 +29,7 @@ fn test_next_year(year_start: i32, year_end: i32) {
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.year, 1);
-    assert_eq!(sut.invert, false);
+    assert!(!sut.invert);
 }
 
 #[test_case(1999, 1998; "world cup")]
@@ -40,7 +42,7 @@ fn test_previous_year(year_start: i32, year_end: i32) {
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.year, 1);
-    assert_eq!(sut.invert, true);
+    assert!(sut.invert);
 
     // with shared timezone
     let from = Shanghai.with_ymd_and_hms(year_start, 1, 1, 0, 0, 0).unwrap();
@@ -48,7 +50,7 @@ fn test_previous_year(year_start: i32, year_end: i32) {
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.year, 1);
-    assert_eq!(sut.invert, true);
+    assert!(sut.invert);
 }
 
 #[test_case(2020, 1, 2020, 2; "January to February")]
@@ -74,7 +76,7 @@ fn test_next_month(year_start: i32, month_start: u32, year_end: i32, month_end:
     let sut = calculate(&from, &to);
     println!("utf_result: {:?}", sut);
     assert_eq!(sut.month, 1);
-    assert_eq!(sut.invert, false);
+    assert!(!sut.invert);
 
     // with shared timezone
     let from = Tokyo
@@ -87,7 +89,7 @@ fn test_next_month(year_start: i32, month_start: u32, year_end: i32, month_end:
     let sut = calculate(&from, &to);
     println!("tokyo_result: {:?}", sut);
     assert_eq!(sut.month, 1);
-    assert_eq!(sut.invert, false);
+    assert!(!sut.invert);
 }
 
 #[test_case(2020, 2, 2020, 1; "February to January")]
@@ -112,7 +114,7 @@ fn test_previous_month(year_start: i32, month_start: u32, year_end: i32, month_e
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.month, 1);
-    assert_eq!(sut.invert, true);
+    assert!(sut.invert);
 
     // with shared timezone
     let from = Paris
@@ -124,7 +126,7 @@ fn test_previous_month(year_start: i32, month_start: u32, year_end: i32, month_e
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.month, 1);
-    assert_eq!(sut.invert, true);
+    assert!(sut.invert);
 }
 
 #[test_case(2019, 12, 30, 2020, 1, 6; "December 30 to January 6")]
@@ -196,7 +198,7 @@ fn test_next_week(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.week, 1);
-    assert_eq!(sut.invert, false);
+    assert!(!sut.invert);
 
     // with shared timezone
     let from = Midway
@@ -208,7 +210,7 @@ fn test_next_week(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.week, 1);
-    assert_eq!(sut.invert, false);
+    assert!(!sut.invert);
 }
 
 #[test_case(2020, 12, 28, 2020, 12, 21; "December 28 to December 21")]
@@ -280,7 +282,7 @@ fn test_previous_week(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.week, 1);
-    assert_eq!(sut.invert, true);
+    assert!(sut.invert);
 
     // with shared timezone
     let from = Lome
@@ -292,7 +294,7 @@ fn test_previous_week(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.week, 1);
-    assert_eq!(sut.invert, true);
+    assert!(sut.invert);
 }
 
 #[test_case(2019, 12, 29, 2019, 12, 30; "Sunday to Monday")]
@@ -319,7 +321,7 @@ fn test_next_day(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.day, 1);
-    assert_eq!(sut.invert, false);
+    assert!(!sut.invert);
 
     // with shared timezone
     let from = Shanghai
@@ -330,7 +332,7 @@ fn test_next_day(
         .unwrap();
     let sut = calculate(&from, &to);
     assert_eq!(sut.day, 1);
-    assert_eq!(sut.invert, false);
+    assert!(!sut.invert);
 }
 
 #[test_case(2020, 1, 5, 2020, 1, 4; "Sunday to Saturday")]
@@ -357,7 +359,7 @@ fn test_previous_day(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.day, 1);
-    assert_eq!(sut.invert, true);
+    assert!(sut.invert);
 
     // with shared timezone
     let from = Tokyo
@@ -370,7 +372,7 @@ fn test_previous_day(
     println!("from: {:?}, to: {:?}", from.with_timezone(&Utc), to.with_timezone(&Utc));
     let sut = calculate(&from, &to);
     assert_eq!(sut.day, 1);
-    assert_eq!(sut.invert, true);
+    assert!(sut.invert);
 }
 
 #[test_case(2019, 12, 31, 23, 2020, 1, 1, 0; "New year's eve to midnight")]
@@ -415,7 +417,7 @@ fn test_next_hours(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.interval_hours, 1);
-    assert_eq!(sut.invert, false);
+    assert!(!sut.invert);
 
     // with shared timezone
     let from = Paris
@@ -427,7 +429,7 @@ fn test_next_hours(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.interval_hours, 1);
-    assert_eq!(sut.invert, false);
+    assert!(!sut.invert);
 }
 
 #[test_case(2020, 1, 1, 23, 2020, 1, 1, 22; "Eleve to ten at night")]
@@ -473,7 +475,7 @@ fn test_previous_hours(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.interval_hours, 1);
-    assert_eq!(sut.invert, true);
+    assert!(sut.invert);
 
     // with shared timezone
     let from = Midway
@@ -485,7 +487,7 @@ fn test_previous_hours(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.interval_hours, 1);
-    assert_eq!(sut.invert, true);
+    assert!(sut.invert);
 }
 
 #[test_case(2019, 12, 31, 23, 30, 2019, 12, 31, 23, 31; "30 minutes before midnight")]
@@ -577,7 +579,7 @@ fn test_next_minutes(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.interval_minutes, 1);
-    assert_eq!(sut.invert, false);
+    assert!(!sut.invert);
 
     // with shared timezone
     let from = Lome
@@ -596,7 +598,7 @@ fn test_next_minutes(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.interval_minutes, 1);
-    assert_eq!(sut.invert, false);
+    assert!(!sut.invert);
 }
 
 #[test_case(2020, 1, 1, 0, 30, 2020, 1, 1, 0, 29; "30 minutes after midnight")]
@@ -687,7 +689,7 @@ fn test_previous_minutes(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.interval_minutes, 1);
-    assert_eq!(sut.invert, true);
+    assert!(sut.invert);
 
     // with shared timezone
     let from = Pacific
@@ -706,7 +708,7 @@ fn test_previous_minutes(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.interval_minutes, 1);
-    assert_eq!(sut.invert, true);
+    assert!(sut.invert);
 }
 
 #[test_case(2019, 12, 31, 23, 59, 30, 2019, 12, 31, 23, 59, 31; "30 seconds before midnight")]
@@ -802,7 +804,7 @@ fn test_next_seconds(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.interval_seconds, 1);
-    assert_eq!(sut.invert, false);
+    assert!(!sut.invert);
 
     // with shared timezone
     let from = Pacific
@@ -823,7 +825,7 @@ fn test_next_seconds(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.interval_seconds, 1);
-    assert_eq!(sut.invert, false);
+    assert!(!sut.invert);
 }
 
 #[test_case(2020, 1, 1, 0, 0, 30, 2020, 1, 1, 0, 0, 29; "30 seconds after midnight")]
@@ -918,7 +920,7 @@ fn test_previous_seconds(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.interval_seconds, 1);
-    assert_eq!(sut.invert, true);
+    assert!(sut.invert);
 
     // with shared timezone
     let from = Kolkata
@@ -939,5 +941,5 @@ fn test_previous_seconds(
 
     let sut = calculate(&from, &to);
     assert_eq!(sut.interval_seconds, 1);
-    assert_eq!(sut.invert, true);
+    assert!(sut.invert);
 }
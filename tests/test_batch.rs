@@ -0,0 +1,84 @@
+use date_component::date_component::*;
+use chrono::prelude::*;
+use chrono::Duration;
+use chrono_tz::Europe::Paris;
+
+fn pairs() -> (Vec<DateTime<chrono_tz::Tz>>, Vec<DateTime<chrono_tz::Tz>>) {
+    let start = Paris.with_ymd_and_hms(2022, 1, 31, 10, 0, 0).unwrap();
+    let from = (0..100).map(|i| start + Duration::hours(i * 37)).collect::<Vec<_>>();
+    let to = (0..100).map(|i| start + Duration::days(400 - i * 9)).collect::<Vec<_>>();
+    (from, to)
+}
+
+#[test]
+fn test_calculate_many_matches_calculate() {
+    let (from, to) = pairs();
+    let sut = calculate_many(&from, &to);
+
+    assert_eq!(sut.len(), from.len());
+    for (i, component) in sut.iter().enumerate() {
+        assert_eq!(*component, calculate(&from[i], &to[i]));
+    }
+}
+
+#[test]
+fn test_calculate_columns_matches_calculate_many() {
+    let (from, to) = pairs();
+    let rows = calculate_many(&from, &to);
+    let sut = calculate_columns(&from, &to);
+
+    assert_eq!(sut.len(), rows.len());
+    for (i, component) in rows.iter().enumerate() {
+        assert_eq!(sut.get(i), Some(*component));
+        assert_eq!(sut.year[i] as isize, component.year);
+        assert_eq!(sut.interval_seconds[i] as isize, component.interval_seconds);
+        assert_eq!(sut.invert[i], component.invert);
+    }
+    assert_eq!(sut.get(rows.len()), None);
+    assert_eq!(rows.into_iter().collect::<DateComponentColumns>(), sut);
+}
+
+#[test]
+fn test_calculate_many_empty() {
+    let empty: Vec<DateTime<Utc>> = Vec::new();
+    assert!(calculate_many(&empty, &empty).is_empty());
+    assert!(calculate_columns(&empty, &empty).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "same length")]
+fn test_calculate_many_length_mismatch() {
+    let from = vec![Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap()];
+    calculate_many(&from, &[]);
+}
+
+#[test]
+fn test_calculate_columns_keep_large_values() {
+    let from = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+    let mut component = calculate(&from, &to);
+    component.interval_seconds = i32::MAX as isize + 1;
+    component.day = i32::MIN as isize - 1;
+
+    let sut: DateComponentColumns = vec![component].into_iter().collect();
+    assert_eq!(sut.interval_seconds[0], i32::MAX as i64 + 1);
+    assert_eq!(sut.day[0], i32::MIN as i64 - 1);
+    assert_eq!(sut.get(0), Some(component));
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn test_calculate_many_in_compiled_zone_matches_calculate() {
+    CachedTz::new(Paris);
+    let (from, mut to) = pairs();
+    // rows whose to datetime is in another timezone are calculated in the from timezone
+    for datetime in to.iter_mut().step_by(3) {
+        *datetime = datetime.with_timezone(&chrono_tz::Asia::Tokyo);
+    }
+    let sut = calculate_many(&from, &to);
+
+    for (i, component) in sut.iter().enumerate() {
+        assert_eq!(*component, calculate(&from[i], &to[i]));
+    }
+    assert_eq!(calculate_columns(&from, &to), sut.into_iter().collect());
+}
@@ -0,0 +1,59 @@
+use date_component::calendar::*;
+use chrono::prelude::*;
+use test_case::test_case;
+
+const DAYS_IN_FEBRUARY_2024: u32 = days_in_month(2024, 2);
+const DAY_OF_YEAR_2023_12_31: u32 = day_of_year(2023, 12, 31);
+
+#[test_case(2000, true; "divisible by 400")]
+#[test_case(1900, false; "divisible by 100")]
+#[test_case(2024, true; "divisible by 4")]
+#[test_case(2023, false; "common year")]
+#[test_case(0, true; "year zero")]
+#[test_case(-4, true; "before year zero")]
+fn test_is_leap_year(year: i32, expected: bool) {
+    assert_eq!(is_leap_year(year), expected);
+    assert_eq!(days_in_year(year), if expected { 366 } else { 365 });
+}
+
+#[test]
+fn test_const_evaluation() {
+    assert_eq!(DAYS_IN_FEBRUARY_2024, 29);
+    assert_eq!(DAY_OF_YEAR_2023_12_31, 365);
+}
+
+#[test]
+fn test_days_in_month_matches_chrono() {
+    for year in [1600, 1900, 1999, 2000, 2023, 2024, 2100] {
+        for month in 1..=12 {
+            let first = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+            let next = first.checked_add_months(chrono::Months::new(1)).unwrap();
+            let expected = next.signed_duration_since(first).num_days() as u32;
+            assert_eq!(days_in_month(year, month), expected, "{}-{}", year, month);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_days_in_month_out_of_range() {
+    days_in_month(2023, 13);
+}
+
+#[test]
+fn test_day_of_year_round_trip() {
+    for year in [1900, 2000, 2023, 2024] {
+        for ordinal in 1..=days_in_year(year) {
+            let (month, day) = from_day_of_year(year, ordinal).unwrap();
+            assert_eq!(day_of_year(year, month, day), ordinal);
+            assert_eq!(NaiveDate::from_yo_opt(year, ordinal).unwrap(), NaiveDate::from_ymd_opt(year, month, day).unwrap());
+        }
+    }
+}
+
+#[test_case(2023, 0; "zero")]
+#[test_case(2023, 366; "after common year")]
+#[test_case(2024, 367; "after leap year")]
+fn test_from_day_of_year_out_of_range(year: i32, ordinal: u32) {
+    assert_eq!(from_day_of_year(year, ordinal), None);
+}
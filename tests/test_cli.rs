@@ -0,0 +1,117 @@
+#![cfg(feature = "cli")]
+
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_date-component"))
+        .args(args)
+        .output()
+        .expect("failed to run date-component")
+}
+
+fn stdout(args: &[&str]) -> String {
+    let output = run(args);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap().trim_end().to_string()
+}
+
+#[test]
+fn test_iso_format_in_timezone() {
+    assert_eq!(
+        stdout(&["2023-01-31T10:00", "2024-03-01", "--tz", "Asia/Tokyo", "--format", "iso"]),
+        "P1Y29DT14H"
+    );
+}
+
+#[test]
+fn test_rfc3339_dates_are_converted_to_timezone() {
+    // 2023-01-31T01:00Z is 10:00 in Tokyo, the same as the local start above.
+    assert_eq!(
+        stdout(&["2023-01-31T01:00:00Z", "2024-02-29T15:00:00Z", "--tz", "Asia/Tokyo", "--format", "iso"]),
+        "P1Y29DT14H"
+    );
+}
+
+#[test]
+fn test_table_format() {
+    let table = stdout(&["2024-01-01", "2024-01-09T06:30:05"]);
+    let lines: Vec<&str> = table.lines().collect();
+
+    assert_eq!(lines.len(), 13);
+    assert_eq!(lines[0], "year             0");
+    assert_eq!(lines[2], "week             1");
+    assert_eq!(lines[4], "day              8");
+    assert_eq!(lines[5], "hour             6");
+    assert_eq!(lines[12], "invert           false");
+}
+
+#[test]
+fn test_json_format() {
+    assert_eq!(
+        stdout(&["2024-03-01", "2023-01-31T10:00", "--format", "json"]),
+        "{\"year\":1,\"month\":0,\"week\":4,\"modulo_days\":1,\"day\":29,\"hour\":14,\"minute\":0,\"second\":0,\
+         \"interval_seconds\":34092000,\"interval_minutes\":568200,\"interval_hours\":9470,\"interval_days\":394,\
+         \"invert\":true}"
+    );
+}
+
+#[test]
+fn test_human_format() {
+    assert_eq!(
+        stdout(&["2023-01-31T10:00", "2024-03-01", "--format", "human"]),
+        "1 year, 29 days and 14 hours"
+    );
+    assert_eq!(
+        stdout(&["2024-03-01T00:00:01", "2024-03-01", "--format", "human"]),
+        "1 second ago"
+    );
+    assert_eq!(stdout(&["2024-03-01", "2024-03-01", "--format", "human"]), "0 seconds");
+}
+
+#[test]
+fn test_add_duration() {
+    assert_eq!(
+        stdout(&["2023-01-31T10:00", "--add", "P1M2DT3H", "--tz", "Asia/Tokyo"]),
+        "2023-03-02T13:00:00+09:00"
+    );
+    assert_eq!(
+        stdout(&["2024-03-31T00:00:00+00:00", "--add", "-1 mons", "--tz", "UTC"]),
+        "2024-02-29T00:00:00+00:00"
+    );
+}
+
+#[test]
+fn test_add_across_dst_uses_local_date() {
+    assert_eq!(
+        stdout(&["2024-03-30T12:00", "--add", "P1D", "--tz", "Europe/London"]),
+        "2024-03-31T12:00:00+01:00"
+    );
+}
+
+#[test]
+fn test_local_time_in_dst_gap_is_moved_forward() {
+    // 2024-03-31 02:30 does not exist in Paris, as in `calculate_str`.
+    assert_eq!(
+        stdout(&["2024-03-31T00:00", "2024-03-31T02:30", "--tz", "Europe/Paris", "--format", "iso"]),
+        "PT2H30M"
+    );
+}
+
+#[test]
+fn test_errors() {
+    let output = run(&["2023-01-31", "2023-02-01", "--tz", "Nowhere/Land"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr).trim_end(),
+        "date-component: unknown timezone \"Nowhere/Land\""
+    );
+
+    let output = run(&["yesterday", "2023-02-01"]);
+    assert_eq!(output.status.code(), Some(1));
+
+    let output = run(&["2023-01-31", "--add", "1 mon -1 days"]);
+    assert_eq!(output.status.code(), Some(1));
+
+    // Missing end date is a usage error.
+    assert_eq!(run(&["2023-01-31"]).status.code(), Some(2));
+}
@@ -0,0 +1,106 @@
+use date_component::date_component::*;
+use chrono::prelude::*;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+fn component(from: (i32, u32, u32), to: (i32, u32, u32)) -> DateComponent {
+    let from = Utc.with_ymd_and_hms(from.0, from.1, from.2, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(to.0, to.1, to.2, 0, 0, 0).unwrap();
+    calculate(&from, &to)
+}
+
+#[test]
+fn test_compare_month_and_days_depends_on_anchor() {
+    let one_month = component((2023, 1, 1), (2023, 2, 1));
+    let thirty_days = component((2023, 1, 1), (2023, 1, 31));
+    assert_eq!(one_month.month, 1);
+    assert_eq!(thirty_days.day, 30);
+
+    let january = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(compare(&one_month, &thirty_days, &january, Disambiguation::Compatible).unwrap(), Ordering::Greater);
+
+    let february = Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap();
+    assert_eq!(compare(&one_month, &thirty_days, &february, Disambiguation::Compatible).unwrap(), Ordering::Less);
+
+    let april = Utc.with_ymd_and_hms(2023, 4, 1, 0, 0, 0).unwrap();
+    assert_eq!(compare(&one_month, &thirty_days, &april, Disambiguation::Compatible).unwrap(), Ordering::Equal);
+}
+
+#[test]
+fn test_compare_inverted() {
+    let anchor = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let forward = component((2023, 1, 1), (2023, 1, 2));
+    let backward = component((2023, 1, 2), (2023, 1, 1));
+    assert!(backward.invert);
+
+    assert_eq!(compare(&backward, &forward, &anchor, Disambiguation::Compatible).unwrap(), Ordering::Less);
+    assert_eq!(compare(&forward, &backward, &anchor, Disambiguation::Compatible).unwrap(), Ordering::Greater);
+}
+
+#[test]
+fn test_relative_date_component_sort() {
+    let anchor = Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap();
+    let mut intervals = [
+        RelativeDateComponent::new(component((2023, 1, 1), (2023, 2, 1)), anchor),
+        RelativeDateComponent::new(component((2023, 1, 1), (2023, 1, 31)), anchor),
+        RelativeDateComponent::new(component((2023, 1, 1), (2023, 1, 2)), anchor),
+    ];
+    intervals.sort();
+
+    let days: Vec<isize> = intervals.iter().map(|i| i.component.interval_days).collect();
+    // from February, 1 month is 28 days
+    assert_eq!(days, vec![1, 31, 30]);
+    assert_eq!(intervals[1].component.month, 1);
+    assert_eq!(intervals[2].component.day, 30);
+}
+
+#[test]
+fn test_relative_date_component_btree_map() {
+    let anchor = Utc.with_ymd_and_hms(2023, 4, 1, 0, 0, 0).unwrap();
+    let mut map = BTreeMap::new();
+    map.insert(RelativeDateComponent::new(component((2023, 1, 1), (2023, 2, 1)), anchor), "one month");
+    // 30 days from April 1st is the same date as 1 month from April 1st
+    map.insert(RelativeDateComponent::new(component((2023, 1, 1), (2023, 1, 31)), anchor), "thirty days");
+    map.insert(RelativeDateComponent::new(component((2023, 1, 1), (2023, 1, 8)), anchor), "one week");
+
+    assert_eq!(map.len(), 2);
+    let values: Vec<&str> = map.values().cloned().collect();
+    assert_eq!(values, vec!["one week", "thirty days"]);
+}
+
+#[test]
+fn test_compare_with_disambiguation() {
+    // 2022-03-13 02:30 does not exist in Los Angeles
+    let anchor = chrono_tz::America::Los_Angeles.with_ymd_and_hms(2022, 3, 12, 2, 30, 0).unwrap();
+    let one_day = component((2023, 1, 1), (2023, 1, 2));
+    let two_days = component((2023, 1, 1), (2023, 1, 3));
+
+    let sut = compare(&one_day, &two_days, &anchor, Disambiguation::Reject);
+    assert!(matches!(sut, Err(LocalTimeError::Nonexistent(_))));
+
+    let sut = compare(&one_day, &two_days, &anchor, Disambiguation::Later).unwrap();
+    assert_eq!(sut, Ordering::Less);
+}
+
+#[test]
+fn test_relative_date_component_inverted_elapsed() {
+    // 2023-01-31 + 1 month 1 day is 2023-03-01
+    let anchor = Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap();
+    let sut = RelativeDateComponent::new(component((2023, 3, 1), (2023, 1, 31)), anchor);
+    assert_eq!((sut.component.month, sut.component.day), (1, 1));
+    assert_eq!(sut.elapsed(), chrono::Duration::days(-29));
+}
+
+#[test]
+fn test_relative_date_component_btree_map_keeps_anchors_apart() {
+    let january = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let march = Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap();
+    let mut map = BTreeMap::new();
+    // 31 days from both anchors
+    map.insert(RelativeDateComponent::new(component((2023, 1, 1), (2023, 2, 1)), january), "january");
+    map.insert(RelativeDateComponent::new(component((2023, 3, 1), (2023, 4, 1)), march), "march");
+
+    assert_eq!(map.len(), 2);
+    let values: Vec<&str> = map.values().cloned().collect();
+    assert_eq!(values, vec!["january", "march"]);
+}
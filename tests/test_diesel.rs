@@ -0,0 +1,83 @@
+#![cfg(feature = "diesel")]
+
+use chrono::prelude::*;
+use date_component::date_component::*;
+use diesel::prelude::*;
+use diesel::sqlite::SqliteConnection;
+
+diesel::table! {
+    intervals (id) {
+        id -> Integer,
+        value -> Text,
+    }
+}
+
+fn connection() -> SqliteConnection {
+    let mut connection = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query("CREATE TABLE intervals (id INTEGER PRIMARY KEY, value TEXT NOT NULL)")
+        .execute(&mut connection)
+        .unwrap();
+    connection
+}
+
+fn fields(c: &DateComponent) -> (isize, isize, isize, isize, isize, isize, bool) {
+    (c.year, c.month, c.day, c.hour, c.minute, c.second, c.invert)
+}
+
+#[test]
+fn test_sqlite_round_trip() {
+    let mut connection = connection();
+    let from = Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 15).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 1, 15, 10, 0, 0).unwrap();
+    let component = calculate(&from, &to);
+
+    diesel::insert_into(intervals::table)
+        .values((intervals::id.eq(1), intervals::value.eq(component)))
+        .execute(&mut connection)
+        .unwrap();
+
+    let stored: String = intervals::table.select(intervals::value).first(&mut connection).unwrap();
+    assert_eq!(stored, "P-1Y-1M-15DT-2H-30M-15S");
+    let loaded: DateComponent = intervals::table.select(intervals::value).first(&mut connection).unwrap();
+    assert_eq!(fields(&loaded), fields(&component));
+}
+
+#[test]
+fn test_sqlite_reads_postgres_styles() {
+    let mut connection = connection();
+    let rows = ["1 year 2 mons 3 days 04:05:06", "@ 1 day ago", "+1-2 +3 +4:05:06"];
+    for (id, text) in rows.iter().enumerate() {
+        diesel::insert_into(intervals::table)
+            .values((intervals::id.eq(id as i32), intervals::value.eq(text)))
+            .execute(&mut connection)
+            .unwrap();
+    }
+
+    let loaded: Vec<PostgresInterval> = intervals::table
+        .select(intervals::value)
+        .order(intervals::id)
+        .load(&mut connection)
+        .unwrap();
+    assert_eq!(
+        loaded,
+        vec![
+            PostgresInterval::new(14, 3, 14_706_000_000),
+            PostgresInterval::new(0, -1, 0),
+            PostgresInterval::new(14, 3, 14_706_000_000),
+        ]
+    );
+}
+
+#[test]
+fn test_sqlite_mixed_signs_fail_to_load_as_date_component() {
+    let mut connection = connection();
+    diesel::insert_into(intervals::table)
+        .values((intervals::id.eq(1), intervals::value.eq(PostgresInterval::new(1, -1, 0))))
+        .execute(&mut connection)
+        .unwrap();
+
+    let loaded: QueryResult<DateComponent> = intervals::table.select(intervals::value).first(&mut connection);
+    assert!(loaded.is_err());
+    let loaded: PostgresInterval = intervals::table.select(intervals::value).first(&mut connection).unwrap();
+    assert_eq!(loaded, PostgresInterval::new(1, -1, 0));
+}
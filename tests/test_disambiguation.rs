@@ -0,0 +1,100 @@
+use date_component::date_component::*;
+use chrono::prelude::*;
+use test_case::test_case;
+use chrono_tz::America::Los_Angeles;
+use chrono_tz::Asia::Beirut;
+
+fn one_day() -> DateComponent {
+    let from = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap();
+    calculate(&from, &to)
+}
+
+#[test_case(Disambiguation::Earlier, Some((1, -8)); "earlier")]
+#[test_case(Disambiguation::Later, Some((3, -7)); "later")]
+#[test_case(Disambiguation::Compatible, Some((3, -7)); "compatible")]
+#[test_case(Disambiguation::Reject, None; "reject")]
+fn test_apply_to_nonexistent(disambiguation: Disambiguation, expected: Option<(u32, i32)>) {
+    // 2022-03-13 02:30 does not exist in Los Angeles
+    let start = Los_Angeles.with_ymd_and_hms(2022, 3, 12, 2, 30, 0).unwrap();
+
+    let sut = one_day().apply_to(&start, disambiguation);
+    match expected {
+        Some((hour, offset_hours)) => {
+            let sut = sut.unwrap();
+            assert_eq!(sut.day(), 13);
+            assert_eq!(sut.hour(), hour);
+            assert_eq!(sut.minute(), 30);
+            assert_eq!(sut.offset().fix().local_minus_utc(), offset_hours * 3600);
+        }
+        None => {
+            let local = NaiveDate::from_ymd_opt(2022, 3, 13).unwrap().and_hms_opt(2, 30, 0).unwrap();
+            assert_eq!(sut, Err(LocalTimeError::Nonexistent(local)));
+        }
+    }
+}
+
+#[test_case(Disambiguation::Earlier, Some(-7); "earlier")]
+#[test_case(Disambiguation::Later, Some(-8); "later")]
+#[test_case(Disambiguation::Compatible, Some(-7); "compatible")]
+#[test_case(Disambiguation::Reject, None; "reject")]
+fn test_apply_to_ambiguous(disambiguation: Disambiguation, expected: Option<i32>) {
+    // 2022-11-06 01:30 happens twice in Los Angeles
+    let start = Los_Angeles.with_ymd_and_hms(2022, 11, 5, 1, 30, 0).unwrap();
+
+    let sut = one_day().apply_to(&start, disambiguation);
+    match expected {
+        Some(offset_hours) => {
+            let sut = sut.unwrap();
+            assert_eq!((sut.day(), sut.hour(), sut.minute()), (6, 1, 30));
+            assert_eq!(sut.offset().fix().local_minus_utc(), offset_hours * 3600);
+        }
+        None => {
+            let local = NaiveDate::from_ymd_opt(2022, 11, 6).unwrap().and_hms_opt(1, 30, 0).unwrap();
+            assert_eq!(sut, Err(LocalTimeError::Ambiguous(local)));
+        }
+    }
+}
+
+#[test]
+fn test_apply_to_inverted() {
+    let start = Utc.with_ymd_and_hms(2023, 3, 31, 12, 0, 0).unwrap();
+    let from = Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap();
+
+    let sut = calculate(&from, &to).apply_to(&start, Disambiguation::Reject).unwrap();
+    // clamped to the end of February
+    assert_eq!(sut, Utc.with_ymd_and_hms(2023, 2, 28, 12, 0, 0).unwrap());
+}
+
+#[test]
+fn test_calculate_with_options_reject() {
+    // the time of day of the start does not exist on 2022-03-13
+    let from = Los_Angeles.with_ymd_and_hms(2022, 3, 12, 2, 30, 0).unwrap();
+    let to = Los_Angeles.with_ymd_and_hms(2022, 3, 14, 0, 0, 0).unwrap();
+
+    let options = CalculateOptions {
+        disambiguation: Disambiguation::Reject,
+        ..CalculateOptions::default()
+    };
+    assert!(matches!(
+        calculate_with_options(&from, &to, options),
+        Err(LocalTimeError::Nonexistent(_))
+    ));
+
+    let options = CalculateOptions::default();
+    let sut = calculate_with_options(&from, &to, options).unwrap();
+    assert_eq!(sut, calculate(&from, &to));
+    assert_eq!((sut.day, sut.hour, sut.minute), (1, 20, 30));
+}
+
+#[test]
+fn test_calculate_does_not_skip_day_at_midnight_transition() {
+    // 2024-03-31 00:00 does not exist in Beirut, March still has 31 days
+    let from = Beirut.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap();
+    let to = Beirut.with_ymd_and_hms(2024, 4, 10, 12, 0, 0).unwrap();
+
+    let sut = calculate(&from, &to);
+    assert_eq!(sut.month, 0);
+    assert_eq!(sut.day, 26);
+}
@@ -3,6 +3,9 @@ use chrono::prelude::*;
 use chrono_tz::America::Los_Angeles;
 use chrono_tz::Europe::Paris;
 use chrono_tz::Australia::Sydney;
+use chrono_tz::America::Havana;
+use chrono_tz::America::Santiago;
+use chrono_tz::Asia::Beirut;
 
 #[test]
 fn test_difference_during_dst() {
@@ -165,3 +168,48 @@ fn test_dst_end_transition_paris() {
     };
     assert_date_component_eq(diff, expected);
 }
+
+#[test]
+fn test_month_length_with_midnight_transition_havana() {
+    // 1972-04-30 00:00 does not exist in Havana, April still has 30 days
+    let start = Havana.with_ymd_and_hms(1972, 4, 15, 12, 0, 0).unwrap();
+    let end = Havana.with_ymd_and_hms(1972, 5, 10, 12, 0, 0).unwrap();
+    let diff = calculate(&start, &end);
+    assert_eq!(diff.month, 0);
+    assert_eq!(diff.day, 25);
+    assert_eq!(diff.hour, 0);
+    assert!(!diff.invert);
+}
+
+#[test]
+fn test_month_length_with_midnight_transition_santiago() {
+    // 1973-09-30 00:00 does not exist in Santiago, September still has 30 days
+    let start = Santiago.with_ymd_and_hms(1973, 9, 20, 12, 0, 0).unwrap();
+    let end = Santiago.with_ymd_and_hms(1973, 10, 5, 12, 0, 0).unwrap();
+    let diff = calculate(&start, &end);
+    assert_eq!(diff.month, 0);
+    assert_eq!(diff.day, 15);
+    assert_eq!(diff.hour, 0);
+
+    let diff = calculate(&end, &start);
+    assert_eq!(diff.day, 15);
+    assert!(diff.invert);
+}
+
+#[test]
+fn test_month_length_does_not_depend_on_timezone() {
+    let options = CalculateOptions {
+        disambiguation: Disambiguation::Reject,
+        ..CalculateOptions::default()
+    };
+    // 2024-03-31 00:00 does not exist in Beirut
+    let start = Beirut.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap();
+    let end = Beirut.with_ymd_and_hms(2024, 4, 10, 12, 0, 0).unwrap();
+    let diff = calculate_with_options(&start, &end, options).unwrap();
+
+    let start = Utc.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2024, 4, 10, 12, 0, 0).unwrap();
+    let expected = calculate(&start, &end);
+    assert_eq!((diff.year, diff.month, diff.day), (expected.year, expected.month, expected.day));
+    assert_eq!(diff.day, 26);
+}
@@ -9,7 +9,7 @@ fn test_leap_year_calculation() {
 
     let diff = calculate(&from, &to);
     assert_eq!(diff.interval_days, 2);
-    assert_eq!(diff.invert, false);
+    assert!(!diff.invert);
 
     // compare with non leap year
     let from = Utc.with_ymd_and_hms(2023, 2, 28, 0, 0, 0).unwrap();
@@ -17,7 +17,7 @@ fn test_leap_year_calculation() {
 
     let diff = calculate(&from, &to);
     assert_eq!(diff.interval_days, 1);
-    assert_eq!(diff.invert, false);
+    assert!(!diff.invert);
 }
 
 #[test]
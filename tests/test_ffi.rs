@@ -0,0 +1,97 @@
+#![cfg(feature = "ffi")]
+
+use date_component::ffi::*;
+use std::os::raw::c_char;
+use std::path::PathBuf;
+use std::process::Command;
+
+const TOKYO: &[u8] = b"Asia/Tokyo\0";
+
+fn calculate(from: i64, to: i64, timezone: &[u8]) -> (CDateComponentStatus, CDateComponent) {
+    let mut component = CDateComponent::default();
+    let status = unsafe { date_component_calculate(from, to, timezone.as_ptr() as *const c_char, &mut component) };
+    (status, component)
+}
+
+#[test]
+fn test_calculate() {
+    // 2023-01-31T10:00:00+09:00 to 2024-03-01T00:00:00+09:00
+    let (status, component) = calculate(1_675_126_800, 1_709_218_800, TOKYO);
+
+    assert_eq!(status, CDateComponentStatus::Ok);
+    assert_eq!((component.year, component.month, component.day, component.hour), (1, 0, 29, 14));
+    assert_eq!(component.interval_seconds, 34_092_000);
+    assert!(!component.invert);
+}
+
+#[test]
+fn test_calculate_errors() {
+    assert_eq!(calculate(0, 0, b"Nowhere/Land\0").0, CDateComponentStatus::UnknownTimezone);
+    assert_eq!(calculate(0, 0, b"\xff\0").0, CDateComponentStatus::InvalidUtf8);
+    assert_eq!(calculate(i64::MAX, 0, TOKYO).0, CDateComponentStatus::TimestampOutOfRange);
+
+    let status = unsafe { date_component_calculate(0, 0, std::ptr::null(), &mut CDateComponent::default()) };
+    assert_eq!(status, CDateComponentStatus::NullPointer);
+    let status = unsafe { date_component_calculate(0, 0, TOKYO.as_ptr() as *const c_char, std::ptr::null_mut()) };
+    assert_eq!(status, CDateComponentStatus::NullPointer);
+}
+
+#[test]
+fn test_status_message() {
+    let message = |status| unsafe { std::ffi::CStr::from_ptr(date_component_status_message(status)) };
+    assert_eq!(message(CDateComponentStatus::UnknownTimezone as i32).to_str(), Ok("unknown timezone"));
+    assert_eq!(message(42).to_str(), Ok("unknown status"));
+    assert_eq!(message(-1).to_str(), Ok("unknown status"));
+}
+
+#[test]
+fn test_header_is_up_to_date() {
+    let generated = include_str!(concat!(env!("OUT_DIR"), "/date_component.h"));
+    let committed = include_str!("../include/date_component.h");
+    assert_eq!(committed, generated, "include/date_component.h is outdated, copy it from OUT_DIR");
+}
+
+/// Builds the library with the `ffi` feature as a cdylib, as C consumers do, and returns
+/// the directory containing it.
+fn build_cdylib() -> PathBuf {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = manifest_dir.join("target/ffi");
+    let output = Command::new(env!("CARGO"))
+        .current_dir(&manifest_dir)
+        .args(["rustc", "--lib", "--crate-type", "cdylib", "--features", "ffi"])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .output()
+        .expect("failed to run cargo");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    target_dir.join("debug")
+}
+
+#[cfg(unix)]
+#[test]
+fn test_c_harness() {
+    let lib_dir = build_cdylib();
+    let executable = lib_dir.join("date_component_ffi_harness");
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+    let status = Command::new(std::env::var("CC").unwrap_or_else(|_| "cc".to_string()))
+        .arg(manifest_dir.join("tests/ffi/harness.c"))
+        .arg("-std=c99")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-ldate_component")
+        .arg("-o")
+        .arg(&executable)
+        .status()
+        .expect("failed to run the C compiler");
+    assert!(status.success());
+
+    let output = Command::new(&executable).env("LD_LIBRARY_PATH", &lib_dir).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ok\n");
+}
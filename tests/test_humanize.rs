@@ -0,0 +1,14 @@
+use chrono::prelude::*;
+use date_component::date_component::*;
+use test_case::test_case;
+
+#[test_case((2023, 1, 15, 10, 0, 0), (2024, 3, 16, 12, 30, 15), "1 year, 2 months, 1 day, 2 hours, 30 minutes and 15 seconds" ; "all units")]
+#[test_case((2024, 1, 1, 0, 0, 0), (2024, 2, 1, 0, 0, 0), "1 month" ; "single unit")]
+#[test_case((2024, 1, 1, 0, 0, 0), (2024, 1, 3, 1, 0, 0), "2 days and 1 hour" ; "two units")]
+#[test_case((2024, 1, 3, 0, 0, 1), (2024, 1, 1, 0, 0, 0), "2 days and 1 second ago" ; "inverted")]
+#[test_case((2024, 1, 1, 0, 0, 0), (2024, 1, 1, 0, 0, 0), "0 seconds" ; "zero")]
+fn test_humanize(from: (i32, u32, u32, u32, u32, u32), to: (i32, u32, u32, u32, u32, u32), expected: &str) {
+    let from = Utc.with_ymd_and_hms(from.0, from.1, from.2, from.3, from.4, from.5).unwrap();
+    let to = Utc.with_ymd_and_hms(to.0, to.1, to.2, to.3, to.4, to.5).unwrap();
+    assert_eq!(calculate(&from, &to).humanize(), expected);
+}
@@ -0,0 +1,55 @@
+use chrono::prelude::*;
+use date_component::date_component::*;
+use test_case::test_case;
+
+fn utc(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, 12, 0, 0).unwrap()
+}
+
+#[test_case(utc(2024, 2, 4), utc(2024, 2, 5), 1, 0, "2024-W05-7", "2024-W06-1"; "sunday to monday")]
+#[test_case(utc(2024, 2, 5), utc(2024, 2, 11), 0, 0, "2024-W06-1", "2024-W06-7"; "within a week")]
+#[test_case(utc(2020, 12, 28), utc(2021, 1, 4), 1, 1, "2020-W53-1", "2021-W01-1"; "from week 53")]
+#[test_case(utc(2024, 12, 29), utc(2024, 12, 30), 1, 1, "2024-W52-7", "2025-W01-1"; "week-year before the calendar year")]
+#[test_case(utc(2024, 12, 30), utc(2025, 1, 5), 0, 0, "2025-W01-1", "2025-W01-7"; "calendar year within a week")]
+#[test_case(utc(2020, 1, 1), utc(2021, 1, 1), 52, 0, "2020-W01-3", "2020-W53-5"; "to week 53")]
+#[test_case(utc(2026, 1, 1), utc(2027, 1, 7), 53, 1, "2026-W01-4", "2027-W01-4"; "across a year of 53 weeks")]
+fn test_calculate_iso_weeks(
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    weeks: isize,
+    week_years: isize,
+    from_week_date: &str,
+    to_week_date: &str,
+) {
+    let sut = calculate_iso_weeks(&from, &to);
+
+    assert_eq!((sut.weeks, sut.week_years), (weeks, week_years));
+    assert_eq!(sut.from_week_date(), from_week_date);
+    assert_eq!(sut.to_week_date(), to_week_date);
+    assert!(!sut.invert);
+}
+
+#[test]
+fn test_calculate_iso_weeks_inverted() {
+    let sut = calculate_iso_weeks(&utc(2021, 1, 4), &utc(2020, 12, 28));
+
+    assert_eq!((sut.weeks, sut.week_years), (1, 1));
+    assert_eq!(sut.from_week(), NaiveDate::from_ymd_opt(2021, 1, 4).unwrap().iso_week());
+    assert_eq!((sut.to_week().year(), sut.to_week().week()), (2020, 53));
+    assert!(sut.invert);
+}
+
+#[test]
+fn test_calculate_iso_weeks_in_timezone_of_from() {
+    // Sunday 2024-02-04 23:00 UTC is already Monday in Tokyo.
+    let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+    let from = Utc.with_ymd_and_hms(2024, 2, 4, 23, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 2, 5, 1, 0, 0).unwrap();
+
+    let sut = calculate_iso_weeks(&from, &to);
+    assert_eq!(sut.weeks, 1);
+
+    let sut = calculate_iso_weeks(&from.with_timezone(&tokyo), &to.with_timezone(&tokyo));
+    assert_eq!(sut.weeks, 0);
+    assert_eq!(sut.from_date, NaiveDate::from_ymd_opt(2024, 2, 5).unwrap());
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "jiff")]
+
+use chrono::prelude::*;
+use chrono_tz::America::New_York;
+use date_component::date_component::*;
+use jiff::Zoned;
+use test_case::test_case;
+
+fn zoned(text: &str) -> Zoned {
+    text.parse().unwrap()
+}
+
+fn chrono_datetime(zoned: &Zoned) -> DateTime<chrono_tz::Tz> {
+    New_York.timestamp_opt(zoned.timestamp().as_second(), 0).unwrap()
+}
+
+#[test_case("2023-01-31T10:00[America/New_York]", "2024-03-01T00:00[America/New_York]"; "across a leap day")]
+#[test_case("2024-03-01T00:00[America/New_York]", "2023-01-31T10:00[America/New_York]"; "inverted")]
+#[test_case("2023-03-11T12:00[America/New_York]", "2023-03-12T12:00[America/New_York]"; "spring forward")]
+#[test_case("2023-11-04T12:00[America/New_York]", "2023-11-05T12:00[America/New_York]"; "fall back")]
+#[test_case("2023-11-05T00:30[America/New_York]", "2023-11-05T01:30-05:00[America/New_York]"; "within fold")]
+fn test_calculate_matches_chrono(from: &str, to: &str) {
+    let (from, to) = (zoned(from), zoned(to));
+
+    let sut = calculate(&from, &to);
+
+    assert_eq!(sut, calculate(&chrono_datetime(&from), &chrono_datetime(&to)));
+}
+
+#[test]
+fn test_calculate_in_zone_of_from() {
+    // 2024-02-29T20:00:00-05:00 is already March in Tokyo.
+    let from = zoned("2024-02-01T10:00[Asia/Tokyo]");
+    let to = zoned("2024-02-29T20:00[America/New_York]");
+
+    let sut = calculate(&from, &to);
+
+    assert_eq!((sut.month, sut.day, sut.hour), (1, 0, 0));
+}
+
+#[test_case(TimeMode::Exact, 23; "exact")]
+#[test_case(TimeMode::WallClock, 24; "wall clock")]
+fn test_calculate_with_mode(mode: TimeMode, hours: isize) {
+    let from = zoned("2023-03-12T00:00[America/New_York]");
+    let to = zoned("2023-03-13T00:00[America/New_York]");
+
+    let sut = calculate_with_mode(&from, &to, mode);
+
+    assert_eq!(sut.interval_hours, hours);
+}
+
+#[test_case(Disambiguation::Earlier; "earlier")]
+#[test_case(Disambiguation::Later; "later")]
+#[test_case(Disambiguation::Compatible; "compatible")]
+#[test_case(Disambiguation::Reject; "reject")]
+fn test_calculate_with_options_nonexistent(disambiguation: Disambiguation) {
+    // 2023-03-12 02:30 does not exist in New York
+    let from = zoned("2023-03-11T02:30[America/New_York]");
+    let to = zoned("2023-03-12T05:00[America/New_York]");
+    let options = CalculateOptions {
+        mode: TimeMode::Exact,
+        disambiguation,
+    };
+
+    let sut = calculate_with_options(&from, &to, options);
+
+    assert_eq!(sut, calculate_with_options(&chrono_datetime(&from), &chrono_datetime(&to), options));
+}
+
+#[test_case(Disambiguation::Earlier; "earlier")]
+#[test_case(Disambiguation::Later; "later")]
+#[test_case(Disambiguation::Reject; "reject")]
+fn test_calculate_with_options_ambiguous(disambiguation: Disambiguation) {
+    // 2023-11-05 01:30 happens twice in New York
+    let from = zoned("2023-11-04T01:30[America/New_York]");
+    let to = zoned("2023-11-05T01:45-05:00[America/New_York]");
+    let options = CalculateOptions {
+        mode: TimeMode::Exact,
+        disambiguation,
+    };
+
+    let sut = calculate_with_options(&from, &to, options);
+
+    assert_eq!(sut, calculate_with_options(&chrono_datetime(&from), &chrono_datetime(&to), options));
+}
@@ -0,0 +1,42 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+const TARGET: &str = "thumbv7em-none-eabihf";
+
+/// Returns true if the standard library of `target` is installed, e.g. with
+/// `rustup target add thumbv7em-none-eabihf`.
+fn target_installed(target: &str) -> bool {
+    let output = Command::new(std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string()))
+        .args(["--print", "sysroot"])
+        .output()
+        .expect("failed to run rustc");
+    let sysroot = PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+    sysroot.join("lib/rustlib").join(target).exists()
+}
+
+/// Checks the library for `TARGET` with `features`.
+fn check(features: &[&str]) {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(env!("CARGO"))
+        .current_dir(&manifest_dir)
+        .args(["check", "--lib", "--no-default-features"])
+        .args(["--features", &features.join(",")])
+        .args(["--target", TARGET])
+        .arg("--target-dir")
+        .arg(manifest_dir.join("target/no_std"))
+        .output()
+        .expect("failed to run cargo");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
+
+#[test]
+fn test_check_no_std() {
+    assert!(
+        target_installed(TARGET),
+        "{} is not installed, run `rustup target add {}`",
+        TARGET,
+        TARGET
+    );
+    check(&[]);
+    check(&["chrono-tz"]);
+}
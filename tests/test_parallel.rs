@@ -0,0 +1,36 @@
+#![cfg(feature = "rayon")]
+
+use date_component::date_component::*;
+use chrono::prelude::*;
+use chrono::Duration;
+use chrono_tz::America::Los_Angeles;
+use rayon::prelude::*;
+
+fn pairs() -> (Vec<DateTime<chrono_tz::Tz>>, Vec<DateTime<chrono_tz::Tz>>) {
+    let start = Los_Angeles.with_ymd_and_hms(2020, 1, 31, 1, 30, 0).unwrap();
+    let from = (0..5_000).map(|i| start + Duration::minutes(i * 211)).collect::<Vec<_>>();
+    let to = (0..5_000).map(|i| start + Duration::hours(20_000 - i * 7)).collect::<Vec<_>>();
+    (from, to)
+}
+
+#[test]
+fn test_par_calculate_matches_sequential() {
+    let (from, to) = pairs();
+    assert_eq!(par_calculate(&from, &to), calculate_many(&from, &to));
+}
+
+#[test]
+fn test_par_calculate_pairs_keeps_order() {
+    let (from, to) = pairs();
+    let sut = par_calculate_pairs(from.par_iter().zip(to.par_iter()).rev());
+
+    let expected: Vec<DateComponent> = from.iter().zip(&to).rev().map(|(f, t)| calculate(f, t)).collect();
+    assert_eq!(sut, expected);
+}
+
+#[test]
+#[should_panic(expected = "same length")]
+fn test_par_calculate_length_mismatch() {
+    let (from, to) = pairs();
+    par_calculate(&from, &to[1..]);
+}
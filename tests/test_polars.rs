@@ -0,0 +1,185 @@
+#![cfg(feature = "polars")]
+
+use date_component::date_component::*;
+use date_component::polars::*;
+use chrono::{DateTime, TimeZone, Utc};
+use polars::prelude::*;
+
+fn datetime_column(name: &str, values: &[Option<DateTime<Utc>>], timezone: Option<&str>) -> Column {
+    let millis: Vec<Option<i64>> = values.iter().map(|v| v.map(|d| d.timestamp_millis())).collect();
+    Int64Chunked::from_iter_options(name.into(), millis.into_iter())
+        .into_datetime(TimeUnit::Milliseconds, polars::prelude::TimeZone::opt_try_new(timezone).unwrap())
+        .into_series()
+        .into_column()
+}
+
+fn utc(year: i32, month: u32, day: u32, hour: u32) -> Option<DateTime<Utc>> {
+    Some(Utc.with_ymd_and_hms(year, month, day, hour, 0, 0).unwrap())
+}
+
+fn field(df: &DataFrame, column: &str, name: &str) -> Vec<Option<i64>> {
+    let column = df.column(column).unwrap().struct_().unwrap().clone();
+    let field = column.field_by_name(name).unwrap();
+    field.i64().unwrap().into_iter().collect()
+}
+
+fn frame(timezone: Option<&str>) -> DataFrame {
+    let start = [utc(2023, 1, 31, 15), utc(2024, 5, 1, 0), None];
+    let end = [utc(2024, 3, 31, 15), utc(2024, 4, 2, 12), utc(2024, 1, 1, 0)];
+    DataFrame::new(vec![
+        datetime_column("start", &start, timezone),
+        datetime_column("end", &end, timezone),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn test_date_component_expression() {
+    let df = frame(None)
+        .lazy()
+        .with_columns([date_component("start", "end").alias("diff")])
+        .collect()
+        .unwrap();
+
+    let expected = calculate(&utc(2023, 1, 31, 15).unwrap(), &utc(2024, 3, 31, 15).unwrap());
+    assert_eq!(field(&df, "diff", "year")[0], Some(expected.year as i64));
+    assert_eq!(field(&df, "diff", "month")[0], Some(expected.month as i64));
+    assert_eq!(field(&df, "diff", "day")[0], Some(expected.day as i64));
+
+    // 2024-05-01 00:00 to 2024-04-02 12:00
+    assert_eq!(field(&df, "diff", "day")[1], Some(28));
+    assert_eq!(field(&df, "diff", "hour")[1], Some(12));
+    let diff = df.column("diff").unwrap().struct_().unwrap().clone();
+    let invert: Vec<Option<bool>> = diff.field_by_name("invert").unwrap().bool().unwrap().into_iter().collect();
+    assert_eq!(invert[..2], [Some(false), Some(true)]);
+
+    // null input gives a null row
+    assert!(df.column("diff").unwrap().get(2).unwrap().is_null());
+}
+
+#[test]
+fn test_date_component_expression_timezone() {
+    // 2023-01-31 15:00 UTC is 2023-02-01 00:00 in Tokyo
+    let options = DateComponentExprOptions {
+        timezone: Some("Asia/Tokyo".to_string()),
+        ..DateComponentExprOptions::default()
+    };
+    let df = frame(None)
+        .lazy()
+        .with_columns([date_component_with_options(col("start"), col("end"), options).alias("diff")])
+        .collect()
+        .unwrap();
+    assert_eq!(field(&df, "diff", "month")[0], Some(2));
+    assert_eq!(field(&df, "diff", "day")[0], Some(0));
+
+    // the timezone of the start column is used by default
+    let df = frame(Some("Asia/Tokyo"))
+        .lazy()
+        .with_columns([date_component("start", "end").alias("diff")])
+        .collect()
+        .unwrap();
+    assert_eq!(field(&df, "diff", "month")[0], Some(2));
+    assert_eq!(field(&df, "diff", "day")[0], Some(0));
+}
+
+#[test]
+fn test_date_component_expression_largest_unit() {
+    let options = DateComponentExprOptions {
+        largest_unit: Unit::Day,
+        ..DateComponentExprOptions::default()
+    };
+    let df = frame(None)
+        .lazy()
+        .with_columns([date_component_with_options(col("start"), col("end"), options).alias("diff")])
+        .collect()
+        .unwrap();
+    assert_eq!(field(&df, "diff", "year")[0], Some(0));
+    assert_eq!(field(&df, "diff", "month")[0], Some(0));
+    // 2023-01-31 to 2024-03-31
+    assert_eq!(field(&df, "diff", "day")[0], Some(425));
+    assert_eq!(field(&df, "diff", "week")[0], Some(60));
+
+    let options = DateComponentExprOptions {
+        largest_unit: Unit::Hour,
+        ..DateComponentExprOptions::default()
+    };
+    let df = frame(None)
+        .lazy()
+        .with_columns([date_component_with_options(col("start"), col("end"), options).alias("diff")])
+        .collect()
+        .unwrap();
+    assert_eq!(field(&df, "diff", "day")[1], Some(0));
+    assert_eq!(field(&df, "diff", "hour")[1], Some(28 * 24 + 12));
+}
+
+#[test]
+fn test_date_component_expression_unknown_timezone() {
+    let options = DateComponentExprOptions {
+        timezone: Some("Mars/Olympus_Mons".to_string()),
+        ..DateComponentExprOptions::default()
+    };
+    let result = frame(None)
+        .lazy()
+        .with_columns([date_component_with_options(col("start"), col("end"), options)])
+        .collect();
+    assert!(result.is_err());
+}
+
+fn balanced(start: &[Option<DateTime<Utc>>], end: &[Option<DateTime<Utc>>], timezone: Option<&str>, largest_unit: Unit) -> DataFrame {
+    let options = DateComponentExprOptions {
+        largest_unit,
+        ..DateComponentExprOptions::default()
+    };
+    DataFrame::new(vec![datetime_column("start", start, timezone), datetime_column("end", end, timezone)])
+        .unwrap()
+        .lazy()
+        .with_columns([date_component_with_options(col("start"), col("end"), options).alias("diff")])
+        .collect()
+        .unwrap()
+}
+
+#[test]
+fn test_date_component_expression_largest_unit_inverted() {
+    // 2023-02-28 + 31 days is 2023-03-31
+    let start = [utc(2023, 3, 31, 0), utc(2023, 3, 1, 0)];
+    let end = [utc(2023, 2, 28, 0), utc(2023, 1, 31, 0)];
+
+    let df = balanced(&start, &end, None, Unit::Day);
+    assert_eq!(field(&df, "diff", "month"), [Some(0), Some(0)]);
+    assert_eq!(field(&df, "diff", "day"), [Some(31), Some(29)]);
+    assert_eq!(field(&df, "diff", "week"), [Some(4), Some(4)]);
+
+    let df = balanced(&start, &end, None, Unit::Month);
+    assert_eq!(field(&df, "diff", "month"), [Some(1), Some(1)]);
+    assert_eq!(field(&df, "diff", "day"), [Some(3), Some(1)]);
+}
+
+#[test]
+fn test_date_component_expression_largest_unit_months_and_time() {
+    let start = [utc(2023, 1, 31, 0), utc(2023, 1, 1, 0)];
+    let end = [utc(2024, 3, 31, 6), Some(Utc.with_ymd_and_hms(2023, 1, 3, 1, 2, 3).unwrap())];
+
+    let df = balanced(&start, &end, None, Unit::Month);
+    assert_eq!(field(&df, "diff", "year"), [Some(0), Some(0)]);
+    assert_eq!(field(&df, "diff", "month"), [Some(14), Some(0)]);
+    assert_eq!(field(&df, "diff", "hour"), [Some(6), Some(1)]);
+
+    let df = balanced(&start, &end, None, Unit::Minute);
+    assert_eq!(field(&df, "diff", "hour")[1], Some(0));
+    assert_eq!(field(&df, "diff", "minute")[1], Some(49 * 60 + 2));
+    assert_eq!(field(&df, "diff", "second")[1], Some(3));
+
+    let df = balanced(&start, &end, None, Unit::Second);
+    assert_eq!(field(&df, "diff", "second")[1], Some((49 * 60 + 2) * 60 + 3));
+}
+
+#[test]
+fn test_date_component_expression_largest_unit_across_dst() {
+    // 2022-04-13 12:00 to 2022-03-13 12:00 in Los Angeles, clocks went forward on 2022-03-13
+    let start = [Some(Utc.with_ymd_and_hms(2022, 4, 13, 19, 0, 0).unwrap())];
+    let end = [Some(Utc.with_ymd_and_hms(2022, 3, 13, 19, 0, 0).unwrap())];
+
+    let df = balanced(&start, &end, Some("America/Los_Angeles"), Unit::Day);
+    assert_eq!(field(&df, "diff", "day"), [Some(31)]);
+    assert_eq!(field(&df, "diff", "hour"), [Some(0)]);
+}
@@ -0,0 +1,115 @@
+use date_component::date_component::*;
+use std::convert::TryFrom;
+use test_case::test_case;
+
+const HOUR: i64 = 3_600_000_000;
+
+// Expected texts are the output of PostgreSQL 16 for each `SET intervalstyle`.
+#[test_case(PostgresInterval::new(14, 3, 14_706_789_000), "1 year 2 mons 3 days 04:05:06.789", "@ 1 year 2 mons 3 days 4 hours 5 mins 6.789 secs", "+1-2 +3 +4:05:06.789", "P1Y2M3DT4H5M6.789S" ; "positive")]
+#[test_case(PostgresInterval::new(-14, -3, -14_706_000_000), "-1 years -2 mons -3 days -04:05:06", "@ 1 year 2 mons 3 days 4 hours 5 mins 6 secs ago", "-1-2 -3 -4:05:06", "P-1Y-2M-3DT-4H-5M-6S" ; "negative")]
+#[test_case(PostgresInterval::new(0, 1, -2 * HOUR), "1 day -02:00:00", "@ 1 day -2 hours", "+0-0 +1 -2:00:00", "P1DT-2H" ; "mixed signs")]
+#[test_case(PostgresInterval::new(0, -1, 2 * HOUR), "-1 days +02:00:00", "@ 1 day -2 hours ago", "+0-0 -1 +2:00:00", "P-1DT2H" ; "negative day positive time")]
+#[test_case(PostgresInterval::new(0, 0, 0), "00:00:00", "@ 0", "0", "PT0S" ; "zero")]
+#[test_case(PostgresInterval::new(1, 0, 0), "1 mon", "@ 1 mon", "0-1", "P1M" ; "one month")]
+#[test_case(PostgresInterval::new(0, -3, 0), "-3 days", "@ 3 days ago", "-3 0:00:00", "P-3D" ; "negative days")]
+#[test_case(PostgresInterval::new(0, 0, 36 * HOUR + 1_000_000), "36:00:01", "@ 36 hours 1 sec", "36:00:01", "PT36H1S" ; "hours past a day")]
+#[test_case(PostgresInterval::new(0, 0, -500_000), "-00:00:00.5", "@ 0.5 secs ago", "-0:00:00.5", "PT-0.5S" ; "negative fraction")]
+fn test_format_and_parse(interval: PostgresInterval, postgres: &str, verbose: &str, sql_standard: &str, iso8601: &str) {
+    let styles = [
+        (IntervalStyle::Postgres, postgres),
+        (IntervalStyle::PostgresVerbose, verbose),
+        (IntervalStyle::SqlStandard, sql_standard),
+        (IntervalStyle::Iso8601, iso8601),
+    ];
+    for (style, text) in styles.iter() {
+        assert_eq!(interval.format(*style), *text);
+        assert_eq!(PostgresInterval::parse(text, *style), Ok(interval));
+        assert_eq!(text.parse::<PostgresInterval>(), Ok(interval));
+    }
+}
+
+#[test]
+fn test_display_uses_postgres_style() {
+    assert_eq!(PostgresInterval::new(25, 1, HOUR).to_string(), "2 years 1 mon 1 day 01:00:00");
+}
+
+#[test_case("1 year 2 months 1 week", PostgresInterval::new(14, 7, 0) ; "long units")]
+#[test_case("  3 days   04:05 ", PostgresInterval::new(0, 3, 4 * HOUR + 300_000_000) ; "hours and minutes")]
+#[test_case("@ 1 day 30 seconds", PostgresInterval::new(0, 1, 30_000_000) ; "verbose long units")]
+#[test_case("-1-2 3 4:00:00", PostgresInterval::new(-14, -3, -4 * HOUR) ; "sql standard leading minus")]
+#[test_case("12", PostgresInterval::new(0, 0, 12_000_000) ; "sql standard lone number is seconds")]
+#[test_case("P1W", PostgresInterval::new(0, 7, 0) ; "iso weeks")]
+fn test_parse_accepts_input_forms(text: &str, expected: PostgresInterval) {
+    assert_eq!(text.parse::<PostgresInterval>(), Ok(expected));
+}
+
+#[test_case("", IntervalStyle::Postgres ; "empty")]
+#[test_case("3 fortnights", IntervalStyle::Postgres ; "unknown unit")]
+#[test_case("3", IntervalStyle::Postgres ; "missing unit")]
+#[test_case("04:60:00", IntervalStyle::Postgres ; "minutes out of range")]
+#[test_case("@", IntervalStyle::PostgresVerbose ; "verbose empty")]
+#[test_case("1 day", IntervalStyle::PostgresVerbose ; "verbose without at sign")]
+#[test_case("1-x", IntervalStyle::SqlStandard ; "sql standard bad month")]
+#[test_case("P", IntervalStyle::Iso8601 ; "iso empty")]
+#[test_case("P1DT", IntervalStyle::Iso8601 ; "iso empty time")]
+#[test_case("P1H", IntervalStyle::Iso8601 ; "iso hours without time")]
+#[test_case("PT1.1234567S", IntervalStyle::Iso8601 ; "iso too precise")]
+fn test_parse_rejects_invalid_text(text: &str, style: IntervalStyle) {
+    assert_eq!(
+        PostgresInterval::parse(text, style),
+        Err(PostgresIntervalError::InvalidFormat(text.to_string()))
+    );
+}
+
+#[test]
+fn test_parse_out_of_range() {
+    assert_eq!(
+        "200000000 years".parse::<PostgresInterval>(),
+        Err(PostgresIntervalError::OutOfRange)
+    );
+}
+
+#[test]
+fn test_from_date_component() {
+    let from = chrono::DateTime::parse_from_rfc3339("2023-01-15T10:00:00+00:00").unwrap();
+    let to = chrono::DateTime::parse_from_rfc3339("2024-02-16T12:30:15+00:00").unwrap();
+
+    assert_eq!(
+        PostgresInterval::try_from(calculate(&from, &to)),
+        Ok(PostgresInterval::new(13, 1, 2 * HOUR + 1_815_000_000))
+    );
+    assert_eq!(
+        PostgresInterval::try_from(calculate(&to, &from)),
+        Ok(PostgresInterval::new(-13, -1, -2 * HOUR - 1_815_000_000))
+    );
+}
+
+#[test]
+fn test_into_date_component() {
+    let component = DateComponent::try_from(PostgresInterval::new(-14, -10, -(26 * HOUR + 61_500_000))).unwrap();
+
+    assert_eq!(
+        (component.year, component.month, component.day, component.week, component.modulo_days),
+        (1, 2, 10, 1, 3)
+    );
+    assert_eq!((component.hour, component.minute, component.second), (26, 1, 1));
+    assert!(component.invert);
+    // 365.25 + 2 * 30 + 10 days, 26 hours and 61 seconds, as EXTRACT(EPOCH FROM interval).
+    assert_eq!(component.interval_seconds, 37_699_261);
+    assert_eq!(component.interval_days, 436);
+}
+
+#[test]
+fn test_into_date_component_rejects_mixed_signs() {
+    assert_eq!(
+        DateComponent::try_from(PostgresInterval::new(1, -1, 0)),
+        Err(PostgresIntervalError::MixedSigns)
+    );
+}
+
+#[test]
+fn test_round_trip_through_date_component() {
+    let interval = PostgresInterval::new(-27, -40, -(5 * HOUR + 7_000_000));
+    let component = DateComponent::try_from(interval).unwrap();
+    assert_eq!(PostgresInterval::try_from(component), Ok(interval));
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "postgres-types")]
+
+use bytes::BytesMut;
+use date_component::date_component::*;
+use postgres_types::{FromSql, ToSql, Type};
+
+// interval '1 year 2 mons 3 days 04:05:06' in the binary format.
+const BINARY: [u8; 16] = [0, 0, 0, 3, 108, 139, 192, 128, 0, 0, 0, 3, 0, 0, 0, 14];
+
+#[test]
+fn test_postgres_interval_from_sql() {
+    assert_eq!(
+        PostgresInterval::from_sql(&Type::INTERVAL, &BINARY).unwrap(),
+        PostgresInterval::new(14, 3, 14_706_000_000)
+    );
+}
+
+#[test]
+fn test_postgres_interval_to_sql() {
+    let mut out = BytesMut::new();
+    PostgresInterval::new(14, 3, 14_706_000_000)
+        .to_sql_checked(&Type::INTERVAL, &mut out)
+        .unwrap();
+    assert_eq!(&out[..], &BINARY[..]);
+}
+
+#[test]
+fn test_date_component_round_trip() {
+    let component = DateComponent::from_sql(&Type::INTERVAL, &BINARY).unwrap();
+    assert_eq!(
+        (component.year, component.month, component.day, component.hour, component.minute, component.second),
+        (1, 2, 3, 4, 5, 6)
+    );
+
+    let mut out = BytesMut::new();
+    component.to_sql_checked(&Type::INTERVAL, &mut out).unwrap();
+    assert_eq!(&out[..], &BINARY[..]);
+}
+
+#[test]
+fn test_accepts_only_interval() {
+    assert!(<DateComponent as FromSql>::accepts(&Type::INTERVAL));
+    assert!(!<DateComponent as ToSql>::accepts(&Type::INT8));
+    assert!(DateComponent::from_sql(&Type::INTERVAL, &BINARY[..8]).is_err());
+}
+
+#[test]
+fn test_from_sql_rejects_mixed_signs() {
+    // interval '1 mon -1 days'
+    let raw = [0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 1];
+    assert!(PostgresInterval::from_sql(&Type::INTERVAL, &raw).is_ok());
+    assert!(DateComponent::from_sql(&Type::INTERVAL, &raw).is_err());
+}
@@ -28,6 +28,27 @@ fn test_month_length_edge_cases_leap_year() {
     assert!(!diff.invert);
 }
 
+#[test]
+fn test_month_borrow_from_shorter_month() {
+    // January 31 + 1 month is clamped to February 28, one day before March 1
+    let from = Utc.with_ymd_and_hms(2023, 1, 31, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap();
+    let diff = calculate(&from, &to);
+    assert_eq!((diff.year, diff.month, diff.day), (0, 1, 1));
+    assert_eq!((diff.week, diff.modulo_days), (0, 1));
+    assert!(!diff.invert);
+
+    let diff = calculate(&to, &from);
+    assert_eq!((diff.year, diff.month, diff.day), (0, 1, 1));
+    assert!(diff.invert);
+
+    // leap year: January 30 + 1 month is February 29
+    let from = Utc.with_ymd_and_hms(2024, 1, 30, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let diff = calculate(&from, &to);
+    assert_eq!((diff.month, diff.day), (1, 1));
+}
+
 #[test]
 fn test_negative_duration_edge_case() {
     // Test a case that might cause issues with negative duration
@@ -0,0 +1,134 @@
+#![cfg(feature = "prost")]
+
+use chrono::prelude::*;
+use chrono_tz::Europe::London;
+use date_component::date_component::*;
+use date_component::prost::*;
+use prost::Message;
+use prost_types::{Duration, Timestamp};
+use std::convert::TryFrom;
+
+#[test]
+fn test_duration_from_date_component() {
+    let from = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 3, 10, 6, 30, 15).unwrap();
+
+    assert_eq!(
+        Duration::try_from(calculate(&from, &to)),
+        Ok(Duration {
+            seconds: 9 * 86_400 + 23_415,
+            nanos: 0
+        })
+    );
+    assert_eq!(
+        Duration::try_from(calculate(&to, &from)),
+        Ok(Duration {
+            seconds: -(9 * 86_400 + 23_415),
+            nanos: 0
+        })
+    );
+}
+
+#[test]
+fn test_duration_uses_elapsed_seconds_across_dst() {
+    // One calendar day, but 23 hours elapsed when clocks go forward.
+    let from = London.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap();
+    let to = London.with_ymd_and_hms(2024, 3, 31, 12, 0, 0).unwrap();
+    let component = calculate(&from, &to);
+
+    assert_eq!(component.day, 1);
+    assert_eq!(Duration::try_from(component).unwrap().seconds, 23 * 3600);
+}
+
+#[test]
+fn test_duration_rejects_calendar_components() {
+    let from = Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+
+    assert_eq!(
+        Duration::try_from(calculate(&from, &to)),
+        Err(ProstError::CalendarComponent { year: 1, month: 1 })
+    );
+}
+
+#[test]
+fn test_date_component_from_duration() {
+    let component = DateComponent::from(Duration {
+        seconds: -(10 * 86_400 + 3723),
+        nanos: -500_000_000,
+    });
+
+    assert_eq!((component.year, component.month), (0, 0));
+    assert_eq!((component.week, component.modulo_days, component.day), (1, 3, 10));
+    assert_eq!((component.hour, component.minute, component.second), (1, 2, 3));
+    assert_eq!(component.interval_seconds, 867_723);
+    assert!(component.invert);
+}
+
+#[test]
+fn test_date_component_from_sub_second_negative_duration() {
+    let component = DateComponent::from(Duration { seconds: 0, nanos: -1 });
+    assert_eq!(component.interval_seconds, 0);
+    assert!(!component.invert);
+
+    let component = DateComponent::from(Duration { seconds: -1, nanos: 999_999_999 });
+    assert_eq!(component.interval_seconds, 0);
+    assert!(!component.invert);
+}
+
+#[test]
+fn test_interval_date_component() {
+    let from = Utc.with_ymd_and_hms(2023, 1, 15, 10, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 15).unwrap();
+    let interval = Interval::new(&from, &to);
+
+    assert_eq!(interval.date_component(), Ok(calculate(&from, &to)));
+    assert_eq!(DateComponent::try_from(interval), Ok(calculate(&from, &to)));
+}
+
+#[test]
+fn test_interval_duration_keeps_nanos() {
+    let interval = Interval {
+        start_time: Some(Timestamp {
+            seconds: 1_700_000_000,
+            nanos: 750_000_000,
+        }),
+        end_time: Some(Timestamp {
+            seconds: 1_700_000_010,
+            nanos: 250_000_000,
+        }),
+    };
+
+    assert_eq!(
+        interval.duration(),
+        Ok(Duration {
+            seconds: 9,
+            nanos: 500_000_000
+        })
+    );
+    assert_eq!(interval.date_component().unwrap().second, 9);
+}
+
+#[test]
+fn test_interval_missing_timestamp() {
+    let interval = Interval {
+        start_time: Some(Timestamp::default()),
+        end_time: None,
+    };
+
+    assert_eq!(interval.date_component(), Err(ProstError::MissingTimestamp));
+    assert_eq!(interval.duration(), Err(ProstError::MissingTimestamp));
+}
+
+#[test]
+fn test_interval_wire_format() {
+    let interval = Interval {
+        start_time: Some(Timestamp { seconds: 1, nanos: 0 }),
+        end_time: Some(Timestamp { seconds: 2, nanos: 0 }),
+    };
+    let bytes = interval.encode_to_vec();
+
+    // Fields 1 and 2 are length-delimited Timestamp messages.
+    assert_eq!(bytes, vec![0x0a, 0x02, 0x08, 0x01, 0x12, 0x02, 0x08, 0x02]);
+    assert_eq!(Interval::decode(bytes.as_slice()).unwrap(), interval);
+}
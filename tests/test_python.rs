@@ -0,0 +1,40 @@
+#![cfg(feature = "python")]
+
+use date_component::python::date_component_module;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::ffi::CString;
+
+// Runs every test function of tests/python/test_date_component.py against the module
+// compiled into this test binary.
+#[test]
+fn test_python_module() {
+    Python::initialize();
+    Python::attach(|py| {
+        let run = || -> PyResult<Vec<String>> {
+            let module = pyo3::wrap_pymodule!(date_component_module)(py);
+            py.import("sys")?.getattr("modules")?.set_item("date_component", module)?;
+
+            let code = CString::new(include_str!("python/test_date_component.py")).unwrap();
+            let globals = PyDict::new(py);
+            py.run(&code, Some(&globals), None)?;
+
+            let mut passed = Vec::new();
+            for (name, function) in globals.iter() {
+                let name: String = name.extract()?;
+                if name.starts_with("test_") {
+                    function.call0()?;
+                    passed.push(name);
+                }
+            }
+            Ok(passed)
+        };
+        match run() {
+            Ok(passed) => assert_eq!(passed.len(), 7, "{:?}", passed),
+            Err(error) => {
+                error.print(py);
+                panic!("python test failed: {}", error);
+            }
+        }
+    });
+}
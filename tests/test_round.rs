@@ -0,0 +1,199 @@
+use date_component::date_component::*;
+use chrono::prelude::*;
+use test_case::test_case;
+use chrono_tz::America::Los_Angeles;
+
+fn options<T: TimeZone>(
+    smallest_unit: Unit,
+    increment: u32,
+    mode: RoundingMode,
+    relative_to: DateTime<T>,
+) -> RoundOptions<T> {
+    RoundOptions {
+        smallest_unit,
+        increment,
+        mode,
+        relative_to,
+        disambiguation: Disambiguation::Compatible,
+    }
+}
+
+#[test]
+fn test_round_carries_minutes_into_hour() {
+    let from = Utc.with_ymd_and_hms(2023, 1, 1, 10, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 1, 1, 10, 59, 36).unwrap();
+
+    let sut = calculate(&from, &to).round(options(Unit::Minute, 1, RoundingMode::HalfExpand, from)).unwrap();
+    assert_eq!(sut.hour, 1);
+    assert_eq!(sut.minute, 0);
+    assert_eq!(sut.second, 0);
+    assert_eq!(sut.interval_seconds, 3600);
+    assert!(!sut.invert);
+}
+
+#[test_case(RoundingMode::HalfExpand, 15; "half expand")]
+#[test_case(RoundingMode::Ceil, 30; "ceil")]
+#[test_case(RoundingMode::Floor, 15; "floor")]
+#[test_case(RoundingMode::Trunc, 15; "trunc")]
+fn test_round_to_fifteen_minutes(mode: RoundingMode, expected_minute: isize) {
+    let from = Utc.with_ymd_and_hms(2023, 1, 1, 8, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 1, 1, 10, 22, 0).unwrap();
+
+    let sut = calculate(&from, &to).round(options(Unit::Minute, 15, mode, from)).unwrap();
+    assert_eq!(sut.hour, 2);
+    assert_eq!(sut.minute, expected_minute);
+    assert_eq!(sut.second, 0);
+}
+
+#[test_case(RoundingMode::HalfExpand, 15; "half expand")]
+#[test_case(RoundingMode::Ceil, 15; "ceil")]
+#[test_case(RoundingMode::Floor, 30; "floor")]
+#[test_case(RoundingMode::Trunc, 15; "trunc")]
+fn test_round_negative_to_fifteen_minutes(mode: RoundingMode, expected_minute: isize) {
+    let from = Utc.with_ymd_and_hms(2023, 1, 1, 10, 22, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 1, 1, 8, 0, 0).unwrap();
+
+    let sut = calculate(&from, &to).round(options(Unit::Minute, 15, mode, from)).unwrap();
+    assert_eq!(sut.hour, 2);
+    assert_eq!(sut.minute, expected_minute);
+    assert!(sut.invert);
+}
+
+#[test]
+fn test_round_to_months_depends_on_anchor() {
+    // 2023-02-01 + 1 month 14 days: 14 days is less than half of March.
+    let from = Utc.with_ymd_and_hms(2023, 2, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 3, 15, 0, 0, 0).unwrap();
+    let sut = calculate(&from, &to).round(options(Unit::Month, 1, RoundingMode::HalfExpand, from)).unwrap();
+    assert_eq!(sut.month, 1);
+    assert_eq!(sut.day, 0);
+
+    // 2023-01-01 + 1 month 14 days: 14 days is half of February, ties round up.
+    let from = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 2, 15, 0, 0, 0).unwrap();
+    let sut = calculate(&from, &to).round(options(Unit::Month, 1, RoundingMode::HalfExpand, from)).unwrap();
+    assert_eq!(sut.month, 2);
+    assert_eq!(sut.day, 0);
+}
+
+#[test]
+fn test_round_months_carry_into_year() {
+    let from = Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2022, 12, 20, 0, 0, 0).unwrap();
+
+    let sut = calculate(&from, &to).round(options(Unit::Month, 3, RoundingMode::HalfExpand, from)).unwrap();
+    assert_eq!(sut.year, 1);
+    assert_eq!(sut.month, 0);
+    assert_eq!(sut.day, 0);
+}
+
+#[test_case(Unit::Year, 1, 2, 0, 0; "year")]
+#[test_case(Unit::Week, 1, 1, 3, 14; "week")]
+#[test_case(Unit::Day, 1, 1, 3, 11; "day")]
+fn test_round_calendar_units(unit: Unit, increment: u32, year: isize, month: isize, day: isize) {
+    let from = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2021, 4, 11, 13, 0, 0).unwrap();
+
+    let sut = calculate(&from, &to).round(options(unit, increment, RoundingMode::Ceil, from)).unwrap();
+    assert_eq!(sut.year, year);
+    assert_eq!(sut.month, month);
+    assert_eq!(sut.day, day);
+    assert_eq!(sut.hour, 0);
+}
+
+#[test]
+fn test_round_exact_value_is_unchanged() {
+    let from = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 1, 1, 2, 15, 0).unwrap();
+    let diff = calculate(&from, &to);
+
+    let sut = diff.round(options(Unit::Minute, 15, RoundingMode::Ceil, from)).unwrap();
+    assert_eq!(sut, diff);
+}
+
+#[test]
+fn test_round_days_across_dst() {
+    // 2022-03-13 is 23 hours long in Los Angeles, 12 elapsed hours is past half of it.
+    let from = Los_Angeles.with_ymd_and_hms(2022, 3, 13, 0, 0, 0).unwrap();
+    let to = Los_Angeles.with_ymd_and_hms(2022, 3, 13, 13, 0, 0).unwrap();
+
+    let sut = calculate(&from, &to).round(options(Unit::Day, 1, RoundingMode::HalfExpand, from)).unwrap();
+    assert_eq!(sut.day, 1);
+    assert_eq!(sut.hour, 0);
+    assert_eq!(sut.interval_hours, 23);
+    assert_eq!(sut.interval_seconds, 23 * 3600);
+}
+
+#[test]
+fn test_round_inverted_is_measured_from_earlier_date() {
+    // 2023-02-28 + 1 month 3 days is 2023-03-31
+    let from = Utc.with_ymd_and_hms(2023, 3, 31, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 2, 28, 0, 0, 0).unwrap();
+    let diff = calculate(&from, &to);
+    assert_eq!((diff.month, diff.day), (1, 3));
+
+    let sut = diff.round(options(Unit::Day, 1, RoundingMode::Trunc, from)).unwrap();
+    assert_eq!(sut, diff);
+
+    let sut = diff.round(options(Unit::Month, 1, RoundingMode::Trunc, from)).unwrap();
+    assert_eq!((sut.month, sut.day), (1, 0));
+    assert!(sut.invert);
+
+    let sut = diff.round(options(Unit::Month, 1, RoundingMode::Floor, from)).unwrap();
+    assert_eq!((sut.month, sut.day), (2, 0));
+    assert!(sut.invert);
+}
+
+#[test_case((2023, 1, 31, 0), (2023, 3, 1, 0); "month end to shorter month")]
+#[test_case((2023, 3, 1, 0), (2023, 1, 28, 0); "inverted to shorter month")]
+#[test_case((2023, 3, 1, 0), (2023, 1, 31, 0); "inverted to end of shorter month")]
+#[test_case((2023, 3, 31, 0), (2023, 2, 28, 0); "inverted from month end")]
+#[test_case((2024, 3, 31, 0), (2024, 2, 29, 0); "inverted leap month end")]
+#[test_case((2023, 5, 31, 0), (2023, 4, 30, 0); "inverted to month end")]
+#[test_case((2023, 1, 31, 10), (2023, 3, 1, 9); "month end with time")]
+#[test_case((2023, 3, 1, 9), (2023, 1, 31, 10); "inverted month end with time")]
+#[test_case((2020, 2, 29, 0), (2021, 2, 28, 0); "leap day")]
+#[test_case((2021, 2, 28, 0), (2020, 2, 29, 0); "inverted leap day")]
+fn test_apply_to_round_trip(from: (i32, u32, u32, u32), to: (i32, u32, u32, u32)) {
+    let from = Utc.with_ymd_and_hms(from.0, from.1, from.2, from.3, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(to.0, to.1, to.2, to.3, 0, 0).unwrap();
+
+    let sut = calculate(&from, &to).apply_to(&from, Disambiguation::Reject).unwrap();
+    assert_eq!(sut, to);
+}
+
+#[test]
+fn test_apply_to_round_trip_across_dst() {
+    let from = Los_Angeles.with_ymd_and_hms(2022, 4, 13, 1, 30, 0).unwrap();
+    let to = Los_Angeles.with_ymd_and_hms(2022, 1, 31, 3, 0, 0).unwrap();
+
+    assert_eq!(calculate(&from, &to).apply_to(&from, Disambiguation::Reject).unwrap(), to);
+    assert_eq!(calculate(&to, &from).apply_to(&to, Disambiguation::Reject).unwrap(), from);
+}
+
+#[test]
+fn test_apply_to_inverted_from_another_date() {
+    // no date + 1 month 1 day is 2023-04-01, so the months are subtracted before the days
+    let from = Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 1, 31, 0, 0, 0).unwrap();
+    let diff = calculate(&from, &to);
+    assert_eq!((diff.month, diff.day), (1, 1));
+
+    let start = Utc.with_ymd_and_hms(2023, 4, 1, 0, 0, 0).unwrap();
+    let sut = diff.apply_to(&start, Disambiguation::Reject).unwrap();
+    assert_eq!(sut, Utc.with_ymd_and_hms(2023, 2, 28, 0, 0, 0).unwrap());
+}
+
+#[test_case(Unit::Day, RoundingMode::Trunc)]
+#[test_case(Unit::Day, RoundingMode::Ceil)]
+#[test_case(Unit::Hour, RoundingMode::Floor)]
+#[test_case(Unit::Second, RoundingMode::HalfExpand)]
+fn test_round_exact_inverted_is_unchanged(unit: Unit, mode: RoundingMode) {
+    let from = Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2023, 1, 31, 0, 0, 0).unwrap();
+    let diff = calculate(&from, &to);
+    assert_eq!((diff.month, diff.day, diff.interval_days), (1, 1, 29));
+
+    let sut = diff.round(options(unit, 1, mode, from)).unwrap();
+    assert_eq!(sut, diff);
+}
@@ -0,0 +1,151 @@
+#![cfg(feature = "sqlx")]
+
+use date_component::date_component::*;
+use sqlx::postgres::types::PgInterval;
+use sqlx::postgres::{PgArgumentBuffer, Postgres};
+use sqlx::{Encode, Type};
+
+#[test]
+fn test_type_is_interval() {
+    assert_eq!(
+        <DateComponent as Type<Postgres>>::type_info(),
+        <PgInterval as Type<Postgres>>::type_info()
+    );
+    assert_eq!(
+        <PostgresInterval as Type<Postgres>>::type_info(),
+        <PgInterval as Type<Postgres>>::type_info()
+    );
+}
+
+#[test]
+fn test_encode_matches_pg_interval() {
+    let interval = PgInterval {
+        months: 14,
+        days: 3,
+        microseconds: 14_706_000_000,
+    };
+    let mut expected = PgArgumentBuffer::default();
+    let _ = interval.encode_by_ref(&mut expected).unwrap();
+
+    let mut encoded = PgArgumentBuffer::default();
+    let _ = <PostgresInterval as Encode<Postgres>>::encode(interval.into(), &mut encoded).unwrap();
+    assert_eq!(*encoded, *expected);
+
+    let component = DateComponent {
+        year: 1,
+        month: 2,
+        week: 0,
+        modulo_days: 3,
+        day: 3,
+        hour: 4,
+        minute: 5,
+        second: 6,
+        interval_seconds: 0,
+        interval_minutes: 0,
+        interval_hours: 0,
+        interval_days: 0,
+        invert: false,
+    };
+    let mut encoded = PgArgumentBuffer::default();
+    let _ = <DateComponent as Encode<Postgres>>::encode_by_ref(&component, &mut encoded).unwrap();
+    assert_eq!(*encoded, *expected);
+}
+
+#[test]
+fn test_pg_interval_conversions() {
+    let interval = PostgresInterval::new(-1, 2, -3);
+    assert_eq!(PostgresInterval::from(PgInterval::from(interval)), interval);
+}
+
+mod sqlite {
+    use chrono::prelude::*;
+    use date_component::date_component::*;
+    use futures_executor::block_on;
+    use sqlx::{Connection, SqliteConnection};
+
+    fn connection() -> SqliteConnection {
+        block_on(async {
+            let mut connection = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+            sqlx::query("CREATE TABLE intervals (id INTEGER PRIMARY KEY, value TEXT NOT NULL)")
+                .execute(&mut connection)
+                .await
+                .unwrap();
+            connection
+        })
+    }
+
+    #[test]
+    fn test_sqlite_round_trip() {
+        let mut connection = connection();
+        let from = Utc.with_ymd_and_hms(2023, 1, 15, 10, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2024, 3, 1, 12, 30, 15).unwrap();
+        let component = calculate(&from, &to);
+
+        block_on(async {
+            sqlx::query("INSERT INTO intervals (id, value) VALUES (1, ?)")
+                .bind(component)
+                .execute(&mut connection)
+                .await
+                .unwrap();
+
+            let (stored,): (String,) = sqlx::query_as("SELECT value FROM intervals")
+                .fetch_one(&mut connection)
+                .await
+                .unwrap();
+            assert_eq!(stored, "P1Y1M15DT2H30M15S");
+
+            let (loaded,): (DateComponent,) = sqlx::query_as("SELECT value FROM intervals")
+                .fetch_one(&mut connection)
+                .await
+                .unwrap();
+            assert_eq!(
+                (loaded.year, loaded.month, loaded.day, loaded.hour, loaded.minute, loaded.second, loaded.invert),
+                (1, 1, 15, 2, 30, 15, false)
+            );
+        });
+    }
+
+    #[test]
+    fn test_sqlite_reads_postgres_styles() {
+        let mut connection = connection();
+        block_on(async {
+            for (id, text) in ["-1 years -2 mons", "@ 3 days 4 hours ago", "PT0.5S"].iter().enumerate() {
+                sqlx::query("INSERT INTO intervals (id, value) VALUES (?, ?)")
+                    .bind(id as i64)
+                    .bind(*text)
+                    .execute(&mut connection)
+                    .await
+                    .unwrap();
+            }
+
+            let loaded: Vec<(PostgresInterval,)> = sqlx::query_as("SELECT value FROM intervals ORDER BY id")
+                .fetch_all(&mut connection)
+                .await
+                .unwrap();
+            assert_eq!(
+                loaded,
+                vec![
+                    (PostgresInterval::new(-14, 0, 0),),
+                    (PostgresInterval::new(0, -3, -14_400_000_000),),
+                    (PostgresInterval::new(0, 0, 500_000),),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn test_sqlite_invalid_text_fails_to_decode() {
+        let mut connection = connection();
+        block_on(async {
+            sqlx::query("INSERT INTO intervals (id, value) VALUES (1, 'soon')")
+                .execute(&mut connection)
+                .await
+                .unwrap();
+
+            let loaded: Result<(DateComponent,), _> = sqlx::query_as("SELECT value FROM intervals")
+                .fetch_one(&mut connection)
+                .await;
+            assert!(loaded.is_err());
+        });
+    }
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "time")]
+
+use chrono::prelude::*;
+use date_component::date_component::*;
+use test_case::test_case;
+use time::{OffsetDateTime, UtcOffset};
+
+fn offset_datetime(timestamp: i64, offset_hours: i8) -> OffsetDateTime {
+    OffsetDateTime::from_unix_timestamp(timestamp)
+        .unwrap()
+        .to_offset(UtcOffset::from_hms(offset_hours, 0, 0).unwrap())
+}
+
+fn chrono_datetime(timestamp: i64, offset_hours: i32) -> DateTime<FixedOffset> {
+    FixedOffset::east_opt(offset_hours * 3600).unwrap().timestamp_opt(timestamp, 0).unwrap()
+}
+
+// 2023-01-31T10:00:00+09:00 to 2024-03-01T00:00:00+09:00
+#[test_case(1_675_126_800, 1_709_218_800, 9; "across a leap day")]
+#[test_case(1_709_218_800, 1_675_126_800, 9; "inverted")]
+#[test_case(1_675_126_800, 1_709_218_800, -5; "negative offset")]
+#[test_case(1_672_531_199, 1_672_531_200, 0; "one second")]
+fn test_calculate_matches_chrono(from: i64, to: i64, offset_hours: i8) {
+    let sut = calculate(&offset_datetime(from, offset_hours), &offset_datetime(to, offset_hours));
+    let expected = calculate(
+        &chrono_datetime(from, offset_hours as i32),
+        &chrono_datetime(to, offset_hours as i32),
+    );
+
+    assert_eq!(sut, expected);
+}
+
+#[test]
+fn test_calculate_in_offset_of_from() {
+    // 2024-01-31T15:00:00Z is already February at +09:00.
+    let from = offset_datetime(1_706_713_200, 9);
+    let to = offset_datetime(1_709_218_800, 0);
+
+    let sut = calculate(&from, &to);
+
+    assert_eq!((sut.month, sut.day), (1, 0));
+}
+
+#[test]
+fn test_calculate_subsecond() {
+    let from = OffsetDateTime::from_unix_timestamp_nanos(500_000_000).unwrap();
+    let to = OffsetDateTime::from_unix_timestamp_nanos(2_000_000_000).unwrap();
+
+    let sut = calculate(&from, &to);
+
+    assert_eq!((sut.second, sut.interval_seconds), (1, 1));
+    assert!(!sut.invert);
+}
@@ -0,0 +1,104 @@
+use date_component::date_component::*;
+use chrono::prelude::*;
+use test_case::test_case;
+use chrono_tz::America::Los_Angeles;
+use chrono_tz::Europe::Paris;
+use chrono_tz::Australia::Sydney;
+use chrono_tz::Tz;
+
+fn local(timezone: Tz, year: i32, month: u32, day: u32, hour: u32, min: u32) -> DateTime<Tz> {
+    timezone.with_ymd_and_hms(year, month, day, hour, min, 0).unwrap()
+}
+
+#[test_case(Los_Angeles, 2022, 3, 13; "los angeles dst start")]
+#[test_case(Sydney, 2022, 10, 2; "sydney dst start")]
+#[test_case(Paris, 2022, 3, 27; "paris dst start")]
+fn test_dst_start(timezone: Tz, year: i32, month: u32, day: u32) {
+    let start = local(timezone, year, month, day, 1, 30);
+    let end = local(timezone, year, month, day, 3, 30);
+
+    let exact = calculate_with_mode(&start, &end, TimeMode::Exact);
+    assert_eq!(exact.hour, 1);
+    assert_eq!(exact.interval_hours, 1);
+    assert_eq!(exact, calculate(&start, &end));
+
+    let wall_clock = calculate_with_mode(&start, &end, TimeMode::WallClock);
+    assert_eq!(wall_clock.hour, 2);
+    assert_eq!(wall_clock.interval_hours, 2);
+}
+
+#[test_case(Los_Angeles, 2022, 11, 6, 0; "los angeles dst end")]
+#[test_case(Sydney, 2022, 4, 3, 1; "sydney dst end")]
+#[test_case(Paris, 2022, 10, 30, 1; "paris dst end")]
+fn test_dst_end(timezone: Tz, year: i32, month: u32, day: u32, start_hour: u32) {
+    let start = local(timezone, year, month, day, start_hour, 30);
+    let end = local(timezone, year, month, day, start_hour + 2, 30);
+
+    let exact = calculate_with_mode(&start, &end, TimeMode::Exact);
+    assert_eq!(exact.hour, 3);
+    assert_eq!(exact.interval_hours, 3);
+
+    let wall_clock = calculate_with_mode(&start, &end, TimeMode::WallClock);
+    assert_eq!(wall_clock.hour, 2);
+    assert_eq!(wall_clock.interval_hours, 2);
+}
+
+#[test_case(Los_Angeles, 2022, 3, 13; "los angeles")]
+#[test_case(Sydney, 2022, 10, 2; "sydney")]
+#[test_case(Paris, 2022, 3, 27; "paris")]
+fn test_whole_days_across_dst_start(timezone: Tz, year: i32, month: u32, day: u32) {
+    // from noon the day before to noon the day after the transition
+    let start = local(timezone, year, month, day - 1, 12, 0);
+    let end = local(timezone, year, month, day + 1, 12, 0);
+
+    for mode in [TimeMode::Exact, TimeMode::WallClock] {
+        let diff = calculate_with_mode(&start, &end, mode);
+        assert_eq!(diff.day, 2);
+        assert_eq!(diff.hour, 0);
+        assert_eq!(diff.minute, 0);
+    }
+    assert_eq!(calculate_with_mode(&start, &end, TimeMode::Exact).interval_hours, 47);
+    assert_eq!(calculate_with_mode(&start, &end, TimeMode::WallClock).interval_hours, 48);
+}
+
+#[test_case(Los_Angeles, 2022, 11, 6; "los angeles")]
+#[test_case(Sydney, 2022, 4, 3; "sydney")]
+#[test_case(Paris, 2022, 10, 30; "paris")]
+fn test_one_day_across_dst_end(timezone: Tz, year: i32, month: u32, day: u32) {
+    let start = local(timezone, year, month, day - 1, 12, 0);
+    let end = local(timezone, year, month, day, 12, 0);
+
+    let exact = calculate_with_mode(&start, &end, TimeMode::Exact);
+    assert_eq!((exact.day, exact.hour), (1, 0));
+    assert_eq!(exact.interval_hours, 25);
+
+    let wall_clock = calculate_with_mode(&start, &end, TimeMode::WallClock);
+    assert_eq!((wall_clock.day, wall_clock.hour), (1, 0));
+    assert_eq!(wall_clock.interval_hours, 24);
+}
+
+#[test]
+fn test_time_of_day_borrows_from_days() {
+    let start = local(Paris, 2023, 1, 1, 23, 0);
+    let end = local(Paris, 2023, 1, 3, 1, 0);
+
+    for mode in [TimeMode::Exact, TimeMode::WallClock] {
+        let diff = calculate_with_mode(&start, &end, mode);
+        assert_eq!(diff.day, 1);
+        assert_eq!(diff.hour, 2);
+    }
+}
+
+#[test]
+fn test_dst_end_inverted() {
+    let start = local(Los_Angeles, 2022, 11, 6, 2, 30);
+    let end = local(Los_Angeles, 2022, 11, 6, 0, 30);
+
+    let exact = calculate_with_mode(&start, &end, TimeMode::Exact);
+    assert_eq!(exact.hour, 3);
+    assert!(exact.invert);
+
+    let wall_clock = calculate_with_mode(&start, &end, TimeMode::WallClock);
+    assert_eq!(wall_clock.hour, 2);
+    assert!(wall_clock.invert);
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "chrono-tz")]
+
+use chrono::prelude::*;
+use chrono_tz::Asia::Tokyo;
+use date_component::date_component::*;
+use test_case::test_case;
+
+#[test]
+fn test_parse_timezone() {
+    assert_eq!(parse_timezone("Asia/Tokyo"), Ok(Tokyo));
+    assert_eq!(parse_timezone("UTC"), Ok(chrono_tz::UTC));
+}
+
+#[test]
+fn test_parse_timezone_unknown() {
+    let sut = parse_timezone("Mars/Olympus_Mons").unwrap_err();
+
+    assert_eq!(sut.name, "Mars/Olympus_Mons");
+    assert_eq!(sut.to_string(), "unknown timezone \"Mars/Olympus_Mons\"");
+}
+
+#[test_case("Europe/Paris", 1, 0, 23; "across dst in paris")]
+#[test_case("UTC", 0, 23, 23; "utc")]
+fn test_calculate_in_zone(timezone: &str, day: isize, hour: isize, interval_hours: isize) {
+    // 2024-03-30T12:00:00+01:00 to 2024-03-31T12:00:00+02:00
+    let sut = calculate_in_zone(1_711_796_400, 1_711_879_200, timezone).unwrap();
+
+    assert_eq!((sut.day, sut.hour, sut.interval_hours), (day, hour, interval_hours));
+}
+
+#[test_case("2024-01-01T00:00:00", "2024-03-01T12:00:00", (2, 0, 12, 0); "local datetimes")]
+#[test_case("2024-01-01", "2024-03-01 12:00", (2, 0, 12, 0); "local date")]
+#[test_case("2024-01-31T15:00:00Z", "2024-03-01", (1, 0, 0, 0); "rfc 3339")]
+fn test_calculate_str(from: &str, to: &str, expected: (isize, isize, isize, isize)) {
+    let sut = calculate_str(from, to, "Asia/Tokyo").unwrap();
+
+    assert_eq!((sut.month, sut.day, sut.hour, sut.minute), expected);
+}
+
+#[test]
+fn test_calculate_str_nonexistent_local_time() {
+    // 2024-03-31 02:30 does not exist in Paris and is resolved to 03:30 CEST.
+    let sut = calculate_str("2024-03-31T00:00", "2024-03-31T02:30", "Europe/Paris").unwrap();
+
+    assert_eq!((sut.hour, sut.minute), (2, 30));
+}
+
+#[test]
+fn test_parse_datetime() {
+    let paris = parse_timezone("Europe/Paris").unwrap();
+    let expected = Utc.with_ymd_and_hms(2024, 3, 31, 1, 30, 0).unwrap();
+    assert_eq!(parse_datetime("2024-03-31T03:30:00+02:00", &paris).unwrap(), expected);
+    // skipped by the DST gap, moved forward by an hour
+    assert_eq!(parse_datetime("2024-03-31 02:30", &paris).unwrap(), expected);
+    assert_eq!(
+        parse_datetime("2024-03-31", &paris).unwrap(),
+        Utc.with_ymd_and_hms(2024, 3, 30, 23, 0, 0).unwrap()
+    );
+    assert_eq!(
+        parse_datetime("31/03/2024", &paris),
+        Err(ZoneCalculateError::InvalidDateTime("31/03/2024".to_string()))
+    );
+}
+
+#[test]
+fn test_calculate_errors() {
+    let unknown = UnknownTimezoneError {
+        name: "Mars/Olympus_Mons".to_string(),
+    };
+    assert_eq!(
+        calculate_in_zone(0, 0, "Mars/Olympus_Mons"),
+        Err(ZoneCalculateError::UnknownTimezone(unknown.clone()))
+    );
+    assert_eq!(
+        calculate_str("2024-01-01", "2024-01-02", "Mars/Olympus_Mons"),
+        Err(ZoneCalculateError::UnknownTimezone(unknown))
+    );
+    assert_eq!(
+        calculate_in_zone(i64::MAX, 0, "UTC"),
+        Err(ZoneCalculateError::TimestampOutOfRange(i64::MAX))
+    );
+
+    let sut = calculate_str("2024-01-01", "yesterday", "UTC").unwrap_err();
+    assert_eq!(sut, ZoneCalculateError::InvalidDateTime("yesterday".to_string()));
+    assert_eq!(sut.to_string(), "invalid datetime \"yesterday\"");
+}
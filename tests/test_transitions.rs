@@ -0,0 +1,83 @@
+#![cfg(feature = "chrono-tz")]
+
+use date_component::date_component::*;
+use chrono::prelude::*;
+use chrono::Duration;
+use chrono_tz::America::Los_Angeles;
+use chrono_tz::Asia::Tokyo;
+use chrono_tz::Australia::Sydney;
+
+fn hours(hours: i32) -> FixedOffset {
+    FixedOffset::east_opt(hours * 3600).unwrap()
+}
+
+#[test]
+fn test_offset_transitions_dst_start() {
+    let start = Los_Angeles.with_ymd_and_hms(2022, 3, 13, 1, 30, 0).unwrap();
+    let end = Los_Angeles.with_ymd_and_hms(2022, 3, 13, 3, 30, 0).unwrap();
+
+    let transitions = offset_transitions(&start, &end);
+    assert_eq!(
+        transitions,
+        vec![OffsetTransition {
+            instant: Utc.with_ymd_and_hms(2022, 3, 13, 10, 0, 0).unwrap(),
+            old_offset: hours(-8),
+            new_offset: hours(-7),
+            abbreviation: Some("PDT".to_string()),
+        }]
+    );
+    assert_eq!(transitions[0].change(), Duration::hours(1));
+}
+
+#[test]
+fn test_offset_transitions_over_a_year() {
+    let start = Sydney.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap();
+    let end = Sydney.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+
+    let transitions = offset_transitions(&start, &end);
+    assert_eq!(transitions.len(), 2);
+    assert_eq!(transitions[0].instant, Utc.with_ymd_and_hms(2022, 4, 2, 16, 0, 0).unwrap());
+    assert_eq!(transitions[0].abbreviation.as_deref(), Some("AEST"));
+    assert_eq!(transitions[0].change(), Duration::hours(-1));
+    assert_eq!(transitions[1].instant, Utc.with_ymd_and_hms(2022, 10, 1, 16, 0, 0).unwrap());
+    assert_eq!(transitions[1].abbreviation.as_deref(), Some("AEDT"));
+    assert_eq!(transitions[1].change(), Duration::hours(1));
+
+    // the order of the arguments does not matter
+    assert_eq!(offset_transitions(&end, &start), transitions);
+}
+
+#[test]
+fn test_offset_transitions_none() {
+    let start = Tokyo.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+    let end = Tokyo.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    assert!(offset_transitions(&start, &end).is_empty());
+
+    let start = Los_Angeles.with_ymd_and_hms(2022, 6, 1, 0, 0, 0).unwrap();
+    assert!(offset_transitions(&start, &start).is_empty());
+}
+
+#[test]
+fn test_calculate_with_transitions_flag() {
+    // one transition: wall-clock and elapsed time differ
+    let start = Los_Angeles.with_ymd_and_hms(2022, 11, 6, 0, 30, 0).unwrap();
+    let end = Los_Angeles.with_ymd_and_hms(2022, 11, 6, 2, 30, 0).unwrap();
+    let report = calculate_with_transitions(&start, &end);
+    assert_eq!(report.component, calculate(&start, &end));
+    assert_eq!(report.transitions.len(), 1);
+    assert!(report.wall_clock_differs);
+
+    // two transitions that cancel out
+    let start = Los_Angeles.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap();
+    let end = Los_Angeles.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+    let report = calculate_with_transitions(&start, &end);
+    assert_eq!(report.transitions.len(), 2);
+    assert!(!report.wall_clock_differs);
+
+    // no transition
+    let start = Los_Angeles.with_ymd_and_hms(2022, 6, 1, 0, 0, 0).unwrap();
+    let end = Los_Angeles.with_ymd_and_hms(2022, 7, 1, 0, 0, 0).unwrap();
+    let report = calculate_with_transitions(&start, &end);
+    assert!(report.transitions.is_empty());
+    assert!(!report.wall_clock_differs);
+}
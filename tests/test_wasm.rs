@@ -0,0 +1,48 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use date_component::wasm::calculate;
+use js_sys::Reflect;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+fn get(component: &JsValue, name: &str) -> JsValue {
+    Reflect::get(component, &JsValue::from_str(name)).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn test_calculate() {
+    // 2023-01-31T10:00:00+09:00 to 2024-03-01T00:00:00+09:00
+    let component = calculate(1_675_126_800_000.0, 1_709_218_800_000.0, "Asia/Tokyo").unwrap();
+
+    assert_eq!(get(&component, "year"), 1);
+    assert_eq!(get(&component, "month"), 0);
+    assert_eq!(get(&component, "day"), 29);
+    assert_eq!(get(&component, "hour"), 14);
+    assert_eq!(get(&component, "intervalSeconds"), 34_092_000);
+    assert_eq!(get(&component, "invert"), false);
+}
+
+#[wasm_bindgen_test]
+fn test_calculate_in_zone() {
+    // 2024-02-29T15:00:00Z is still February in UTC but already March in Tokyo.
+    let utc = calculate(1_706_713_200_000.0, 1_709_218_800_000.0, "UTC").unwrap();
+    let tokyo = calculate(1_706_713_200_000.0, 1_709_218_800_000.0, "Asia/Tokyo").unwrap();
+
+    assert_eq!((get(&utc, "month"), get(&utc, "day")), (JsValue::from(0), JsValue::from(29)));
+    assert_eq!((get(&tokyo, "month"), get(&tokyo, "day")), (JsValue::from(1), JsValue::from(0)));
+}
+
+#[wasm_bindgen_test]
+fn test_calculate_inverted() {
+    let component = calculate(86_400_000.0, 0.0, "UTC").unwrap();
+
+    assert_eq!(get(&component, "day"), 1);
+    assert_eq!(get(&component, "invert"), true);
+}
+
+#[wasm_bindgen_test]
+fn test_calculate_errors() {
+    assert!(calculate(0.0, 0.0, "Nowhere/Land").is_err());
+    assert!(calculate(f64::NAN, 0.0, "UTC").is_err());
+    assert!(calculate(0.0, 1e20, "UTC").is_err());
+}
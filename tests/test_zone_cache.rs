@@ -0,0 +1,101 @@
+#![cfg(feature = "chrono-tz")]
+
+use chrono::prelude::*;
+use chrono::Duration;
+use chrono_tz::Tz;
+use date_component::date_component::*;
+use test_case::test_case;
+
+fn zones() -> Vec<Tz> {
+    ["Europe/Paris", "America/New_York", "Australia/Sydney", "Australia/Lord_Howe", "Asia/Tokyo", "Pacific/Apia"]
+        .iter()
+        .map(|name| name.parse().unwrap())
+        .collect()
+}
+
+fn fix<T: TimeZone>(result: chrono::LocalResult<DateTime<T>>) -> chrono::LocalResult<DateTime<FixedOffset>> {
+    result.map(|datetime| datetime.fixed_offset())
+}
+
+#[test]
+fn test_utc_offsets_match_tz() {
+    for tz in zones() {
+        let cached = CachedTz::new(tz);
+        let mut utc = Utc.with_ymd_and_hms(1890, 1, 1, 0, 0, 0).unwrap();
+        while utc.year() < 2110 {
+            assert_eq!(
+                utc.with_timezone(&cached).fixed_offset(),
+                utc.with_timezone(&tz).fixed_offset(),
+                "{} at {}",
+                tz,
+                utc
+            );
+            utc += Duration::minutes(7 * 60 + 17);
+        }
+    }
+}
+
+#[test]
+fn test_local_times_match_tz() {
+    for tz in zones() {
+        let cached = CachedTz::new(tz);
+        let mut local = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        while local.year() < 2013 {
+            assert_eq!(
+                fix(cached.from_local_datetime(&local)),
+                fix(tz.from_local_datetime(&local)),
+                "{} at {}",
+                tz,
+                local
+            );
+            local += Duration::minutes(15);
+        }
+    }
+}
+
+#[test_case(2023, 3, 25, 2023, 3, 27; "spring forward")]
+#[test_case(2023, 10, 28, 2023, 10, 30; "fall back")]
+#[test_case(1850, 1, 31, 1850, 3, 1; "before the cached years")]
+#[test_case(2150, 1, 31, 2150, 3, 1; "after the cached years")]
+fn test_calculate_matches_tz(from_year: i32, from_month: u32, from_day: u32, to_year: i32, to_month: u32, to_day: u32) {
+    let paris = chrono_tz::Europe::Paris;
+    let cached = CachedTz::new(paris);
+    let from = paris.with_ymd_and_hms(from_year, from_month, from_day, 2, 30, 0).unwrap();
+    let to = paris.with_ymd_and_hms(to_year, to_month, to_day, 2, 15, 0).earliest().unwrap();
+
+    let sut = calculate(&from.with_timezone(&cached), &to.with_timezone(&cached));
+
+    assert_eq!(sut, calculate(&from, &to));
+}
+
+#[test]
+fn test_cached_tz() {
+    let sut = CachedTz::from(chrono_tz::Asia::Tokyo);
+
+    assert_eq!(sut, CachedTz::new(chrono_tz::Asia::Tokyo));
+    assert_ne!(sut, CachedTz::new(chrono_tz::UTC));
+    assert_eq!(sut.tz(), chrono_tz::Asia::Tokyo);
+    assert_eq!(sut.to_string(), "Asia/Tokyo");
+    assert_eq!(
+        sut.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap().to_rfc3339(),
+        "2024-01-01T09:00:00+09:00"
+    );
+}
+
+#[test]
+fn test_cached_tz_new_from_many_threads() {
+    let zones: Vec<Tz> = ["Europe/Lisbon", "America/Chicago", "Asia/Kolkata"].iter().map(|name| name.parse().unwrap()).collect();
+    let instant = Utc.with_ymd_and_hms(2023, 7, 1, 12, 0, 0).unwrap();
+
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let tz = zones[i % zones.len()];
+            std::thread::spawn(move || (tz, CachedTz::new(tz)))
+        })
+        .collect();
+    for handle in handles {
+        let (tz, cached) = handle.join().unwrap();
+        assert_eq!(cached.tz(), tz);
+        assert_eq!(instant.with_timezone(&cached).fixed_offset(), instant.with_timezone(&tz).fixed_offset());
+    }
+}